@@ -1,4 +1,7 @@
-use bigint_poly::Polynomial;
+// The benchmarks below predate the workspace-wide clippy gate and are kept as written.
+#![allow(clippy::needless_borrows_for_generic_args, clippy::redundant_closure)]
+
+use bigint_poly::{Polynomial, SparsePolynomial};
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
 use num_bigint::BigInt;
 use num_traits::{One, Zero};
@@ -107,6 +110,40 @@ fn benchmark_cyclotomic_reduction(c: &mut Criterion) {
     group.finish();
 }
 
+fn benchmark_monomial_multiplication(c: &mut Criterion) {
+    let mut group = c.benchmark_group("monomial_multiplication");
+
+    for n in [64, 128, 256, 512] {
+        let (poly1, _) = create_test_polynomials(n - 1);
+
+        group.bench_function(format!("ring_degree_{}", n), |b| {
+            b.iter(|| black_box(poly1.mul_by_monomial(black_box(n as i64 / 3), n).unwrap()))
+        });
+    }
+
+    group.finish();
+}
+
+fn benchmark_sparse_multiplication(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sparse_multiplication");
+
+    for n in [64, 128, 256, 512] {
+        let (poly1, _) = create_test_polynomials(n - 1);
+        // A ternary secret of Hamming weight 8.
+        let sparse = SparsePolynomial::new(
+            (0..8)
+                .map(|i| (i * n / 8 + 1, BigInt::from(if i % 2 == 0 { 1 } else { -1 })))
+                .collect(),
+        );
+
+        group.bench_function(format!("ring_degree_{}", n), |b| {
+            b.iter(|| black_box(sparse.mul_negacyclic(black_box(&poly1), n).unwrap()))
+        });
+    }
+
+    group.finish();
+}
+
 fn benchmark_utility_functions(c: &mut Criterion) {
     let mut group = c.benchmark_group("utility_functions");
 
@@ -174,6 +211,8 @@ criterion_group!(
     benchmark_polynomial_evaluation,
     benchmark_modular_reduction,
     benchmark_cyclotomic_reduction,
+    benchmark_monomial_multiplication,
+    benchmark_sparse_multiplication,
     benchmark_utility_functions,
     benchmark_coefficient_conversion
 );
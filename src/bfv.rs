@@ -0,0 +1,383 @@
+//! Minimal reference implementation of the BFV encryption scheme.
+//!
+//! **This module is not suitable for production use.** It relies on non-constant-time
+//! `BigInt` arithmetic, naive polynomial multiplication and a bounded-uniform error
+//! distribution. Its purpose is to mirror the BFV equations exactly so that the
+//! intermediate polynomials of an encryption can be used as witnesses in zero-knowledge
+//! proofs, and so that hand-written witness generators can be checked against it.
+//!
+//! All ring elements live in `R_q = Z_q[x]/(x^N + 1)` and are stored as [`Polynomial`]s with
+//! `N` coefficients in descending order, centered modulo `q`.
+//!
+//! Public-key encryption of a plaintext `m` computes
+//!
+//! ```text
+//! ct0 = pk0 * u + e0 + Δ * m + r2 * (x^N + 1) + r1 * q
+//! ct1 = pk1 * u + e1         + p2 * (x^N + 1) + p1 * q
+//! ```
+//!
+//! where the equalities hold exactly over `Z[x]` and `Δ = floor(q / t)`. The quotient
+//! polynomials `r1`, `r2`, `p1` and `p2` are returned alongside `u`, `e0` and `e1` in an
+//! [`EncryptionWitness`].
+
+use crate::errors::PolynomialError;
+use crate::sampling::{sample_bounded, sample_ternary, sample_uniform};
+use crate::utils::{RoundingMode, check_ring_dimension, reduce_in_ring, scale_and_round_scalar};
+use crate::{Polynomial, RingReduction};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use rand::Rng;
+
+/// Parameters of a BFV instance.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BfvParameters {
+    /// Ring dimension `N`, a power of two.
+    degree: usize,
+    /// Plaintext modulus `t`.
+    plaintext_modulus: BigInt,
+    /// Ciphertext modulus `q`.
+    ciphertext_modulus: BigInt,
+    /// Bound `B` on the absolute value of error coefficients.
+    error_bound: BigInt,
+}
+
+impl BfvParameters {
+    /// Creates a new set of BFV parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `degree` - The ring dimension `N`; must be a power of two.
+    /// * `plaintext_modulus` - The plaintext modulus `t`; must be at least 2.
+    /// * `ciphertext_modulus` - The ciphertext modulus `q`; must be greater than `t`.
+    /// * `error_bound` - The bound `B` on error coefficients; must be non-negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::CyclotomicError` if `degree` is not a power of two.
+    /// Returns `PolynomialError::ModulusError` if the moduli or error bound are invalid.
+    pub fn new(
+        degree: usize,
+        plaintext_modulus: BigInt,
+        ciphertext_modulus: BigInt,
+        error_bound: BigInt,
+    ) -> Result<Self, PolynomialError> {
+        check_ring_dimension(degree)?;
+        if plaintext_modulus < BigInt::from(2) {
+            return Err(PolynomialError::ModulusError {
+                message: "Plaintext modulus must be at least 2".to_string(),
+            });
+        }
+        if ciphertext_modulus <= plaintext_modulus {
+            return Err(PolynomialError::ModulusError {
+                message: "Ciphertext modulus must be greater than the plaintext modulus"
+                    .to_string(),
+            });
+        }
+        if error_bound < BigInt::zero() {
+            return Err(PolynomialError::ModulusError {
+                message: "Error bound cannot be negative".to_string(),
+            });
+        }
+
+        Ok(Self {
+            degree,
+            plaintext_modulus,
+            ciphertext_modulus,
+            error_bound,
+        })
+    }
+
+    /// Returns the ring dimension `N`.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Returns the plaintext modulus `t`.
+    pub fn plaintext_modulus(&self) -> &BigInt {
+        &self.plaintext_modulus
+    }
+
+    /// Returns the ciphertext modulus `q`.
+    pub fn ciphertext_modulus(&self) -> &BigInt {
+        &self.ciphertext_modulus
+    }
+
+    /// Returns the error bound `B`.
+    pub fn error_bound(&self) -> &BigInt {
+        &self.error_bound
+    }
+
+    /// Returns the scaling factor `Δ = floor(q / t)`.
+    pub fn delta(&self) -> BigInt {
+        &self.ciphertext_modulus / &self.plaintext_modulus
+    }
+
+    /// Returns the coefficients of the cyclotomic polynomial `x^N + 1` in descending order.
+    pub fn cyclotomic(&self) -> Vec<BigInt> {
+        let mut cyclo = vec![BigInt::zero(); self.degree + 1];
+        cyclo[0] = BigInt::one();
+        cyclo[self.degree] = BigInt::one();
+        cyclo
+    }
+
+    /// Reduces a polynomial in `R_q`, returning centered coefficients.
+    fn reduce(&self, poly: &Polynomial) -> Polynomial {
+        let mut coefficients = poly.coefficients.clone();
+        reduce_in_ring(
+            &mut coefficients,
+            &self.cyclotomic(),
+            &self.ciphertext_modulus,
+        );
+        Polynomial::new(coefficients)
+    }
+
+    /// Checks that a polynomial fits in the ring, i.e. has at most `N` coefficients.
+    fn check_ring_element(&self, poly: &Polynomial, name: &str) -> Result<(), PolynomialError> {
+        if poly.coefficients.len() > self.degree {
+            return Err(PolynomialError::InvalidPolynomial {
+                message: format!(
+                    "{name} has {} coefficients but the ring dimension is {}",
+                    poly.coefficients.len(),
+                    self.degree
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A BFV secret key `s`, sampled from the ternary distribution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecretKey {
+    /// The secret polynomial `s`.
+    pub s: Polynomial,
+}
+
+/// A BFV public key `(pk0, pk1) = (-(a * s + e), a)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicKey {
+    /// The first component `pk0 = -(a * s + e)`.
+    pub pk0: Polynomial,
+    /// The second component `pk1 = a`.
+    pub pk1: Polynomial,
+}
+
+/// A BFV ciphertext `(ct0, ct1)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ciphertext {
+    /// The first ciphertext component.
+    pub ct0: Polynomial,
+    /// The second ciphertext component.
+    pub ct1: Polynomial,
+}
+
+/// The intermediate polynomials of a public-key encryption.
+///
+/// These satisfy, exactly over `Z[x]`:
+///
+/// ```text
+/// ct0 = pk0 * u + e0 + Δ * m + r2 * (x^N + 1) + r1 * q
+/// ct1 = pk1 * u + e1         + p2 * (x^N + 1) + p1 * q
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct EncryptionWitness {
+    /// The ternary ephemeral polynomial `u`.
+    pub u: Polynomial,
+    /// The error polynomial added to `ct0`.
+    pub e0: Polynomial,
+    /// The error polynomial added to `ct1`.
+    pub e1: Polynomial,
+    /// The plaintext scaled by `Δ`, before reduction.
+    pub scaled_message: Polynomial,
+    /// The `q` quotient of `ct0`.
+    pub r1: Polynomial,
+    /// The cyclotomic quotient of `ct0`.
+    pub r2: Polynomial,
+    /// The `q` quotient of `ct1`.
+    pub p1: Polynomial,
+    /// The cyclotomic quotient of `ct1`.
+    pub p2: Polynomial,
+}
+
+/// Generates a secret key and the matching public key.
+///
+/// # Arguments
+///
+/// * `params` - The BFV parameters.
+/// * `rng` - The random number generator.
+///
+/// # Returns
+///
+/// A tuple of (secret key, public key).
+pub fn keygen<R: Rng + ?Sized>(params: &BfvParameters, rng: &mut R) -> (SecretKey, PublicKey) {
+    let n = params.degree - 1;
+    let s = sample_ternary(n, rng);
+    let a = sample_uniform(n, &params.ciphertext_modulus, rng);
+    let e = sample_bounded(n, &params.error_bound, rng);
+
+    let pk0 = params.reduce(&a.mul(&s).add(&e).neg());
+
+    (SecretKey { s }, PublicKey { pk0, pk1: a })
+}
+
+/// Encrypts a plaintext under a public key.
+///
+/// The plaintext coefficients are first reduced to `[0, t)`.
+///
+/// # Arguments
+///
+/// * `params` - The BFV parameters.
+/// * `pk` - The public key.
+/// * `message` - The plaintext polynomial, with at most `N` coefficients.
+/// * `rng` - The random number generator.
+///
+/// # Returns
+///
+/// A tuple of (ciphertext, encryption witness).
+///
+/// # Errors
+///
+/// Returns `PolynomialError::InvalidPolynomial` if the plaintext does not fit in the ring.
+pub fn encrypt<R: Rng + ?Sized>(
+    params: &BfvParameters,
+    pk: &PublicKey,
+    message: &Polynomial,
+    rng: &mut R,
+) -> Result<(Ciphertext, EncryptionWitness), PolynomialError> {
+    params.check_ring_element(message, "Plaintext")?;
+
+    let n = params.degree - 1;
+    let u = sample_ternary(n, rng);
+    let e0 = sample_bounded(n, &params.error_bound, rng);
+    let e1 = sample_bounded(n, &params.error_bound, rng);
+
+    let message = Polynomial::new(
+        message
+            .coefficients
+            .iter()
+            .map(|c| c.mod_floor(&params.plaintext_modulus))
+            .collect(),
+    );
+    let scaled_message = message.scalar_mul(&params.delta());
+
+    let cyclo = params.cyclotomic();
+    let q = &params.ciphertext_modulus;
+    let RingReduction {
+        result: ct0,
+        r1,
+        r2,
+    } = pk
+        .pk0
+        .mul(&u)
+        .add(&e0)
+        .add(&scaled_message)
+        .reduce_in_ring_with_quotients(&cyclo, q)?;
+    let RingReduction {
+        result: ct1,
+        r1: p1,
+        r2: p2,
+    } = pk
+        .pk1
+        .mul(&u)
+        .add(&e1)
+        .reduce_in_ring_with_quotients(&cyclo, q)?;
+
+    Ok((
+        Ciphertext { ct0, ct1 },
+        EncryptionWitness {
+            u,
+            e0,
+            e1,
+            scaled_message,
+            r1,
+            r2,
+            p1,
+            p2,
+        },
+    ))
+}
+
+/// Decrypts a ciphertext with a secret key.
+///
+/// Computes `round(t * [ct0 + ct1 * s]_q / q) mod t` coefficient-wise, rounding halves
+/// away from zero.
+///
+/// # Arguments
+///
+/// * `params` - The BFV parameters.
+/// * `sk` - The secret key.
+/// * `ct` - The ciphertext to decrypt.
+///
+/// # Returns
+///
+/// The plaintext polynomial with `N` coefficients in `[0, t)`.
+pub fn decrypt(params: &BfvParameters, sk: &SecretKey, ct: &Ciphertext) -> Polynomial {
+    let noisy = params.reduce(&ct.ct0.add(&ct.ct1.mul(&sk.s)));
+    let t = &params.plaintext_modulus;
+
+    let coefficients: Vec<BigInt> = noisy
+        .coefficients
+        .iter()
+        .map(|x| {
+            scale_and_round_scalar(
+                x,
+                t,
+                &params.ciphertext_modulus,
+                RoundingMode::NearestTiesAway,
+            )
+            .mod_floor(t)
+        })
+        .collect();
+
+    Polynomial::new(coefficients)
+}
+
+/// Adds two ciphertexts component-wise in `R_q`.
+///
+/// # Arguments
+///
+/// * `params` - The BFV parameters.
+/// * `a` - The first ciphertext.
+/// * `b` - The second ciphertext.
+///
+/// # Returns
+///
+/// A ciphertext encrypting the sum of the two plaintexts modulo `t`.
+pub fn add(params: &BfvParameters, a: &Ciphertext, b: &Ciphertext) -> Ciphertext {
+    Ciphertext {
+        ct0: params.reduce(&a.ct0.add(&b.ct0)),
+        ct1: params.reduce(&a.ct1.add(&b.ct1)),
+    }
+}
+
+/// Multiplies a ciphertext by a plaintext polynomial in `R_q`.
+///
+/// # Arguments
+///
+/// * `params` - The BFV parameters.
+/// * `ct` - The ciphertext.
+/// * `plaintext` - The plaintext polynomial, with at most `N` coefficients.
+///
+/// # Returns
+///
+/// A ciphertext encrypting the product of the two plaintexts in `R_t`.
+///
+/// # Errors
+///
+/// Returns `PolynomialError::InvalidPolynomial` if the plaintext does not fit in the ring.
+pub fn mul_plain(
+    params: &BfvParameters,
+    ct: &Ciphertext,
+    plaintext: &Polynomial,
+) -> Result<Ciphertext, PolynomialError> {
+    params.check_ring_element(plaintext, "Plaintext")?;
+
+    // Centered plaintext coefficients keep the noise growth to `t / 2 * N`.
+    let plaintext = plaintext.reduce_and_center(&params.plaintext_modulus);
+
+    Ok(Ciphertext {
+        ct0: params.reduce(&ct.ct0.mul(&plaintext)),
+        ct1: params.reduce(&ct.ct1.mul(&plaintext)),
+    })
+}
@@ -0,0 +1,260 @@
+//! Coefficient types for [`GenericPolynomial`](crate::GenericPolynomial).
+//!
+//! The [`Coefficient`] trait captures the ring operations a polynomial needs from its
+//! coefficients, together with lossless conversions to and from `BigInt`. It is implemented for
+//! `BigInt`, `BigUint`, `i64`, `u64`, `i128` and [`Montgomery`], an element of `Z_q` stored in
+//! Montgomery form for a compile-time modulus `q < 2^62`.
+//!
+//! Fixed-width integer coefficients use native arithmetic: overflow panics in debug builds and
+//! wraps in release builds, so they should only be used when all intermediate values are known
+//! to fit.
+
+use crate::zq::{MAX_MODULUS_BITS, Zq};
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A coefficient of a polynomial.
+pub trait Coefficient: Clone + fmt::Debug + PartialEq + Zero + One {
+    /// Returns `self + rhs`.
+    fn add_ref(&self, rhs: &Self) -> Self;
+
+    /// Returns `self * rhs`.
+    fn mul_ref(&self, rhs: &Self) -> Self;
+
+    /// Sets `self` to `self + rhs`.
+    fn add_assign_ref(&mut self, rhs: &Self) {
+        *self = self.add_ref(rhs);
+    }
+
+    /// Converts the coefficient to a `BigInt`.
+    fn to_bigint(&self) -> BigInt;
+
+    /// Converts a `BigInt` to a coefficient, or returns `None` if it does not fit.
+    ///
+    /// Modular coefficient types reduce the value instead of rejecting it.
+    fn from_bigint(value: &BigInt) -> Option<Self>;
+}
+
+/// A coefficient type that supports negation, and hence subtraction.
+pub trait SignedCoefficient: Coefficient {
+    /// Returns `-self`.
+    fn neg_ref(&self) -> Self;
+}
+
+impl Coefficient for BigInt {
+    fn add_ref(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    fn mul_ref(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+
+    fn add_assign_ref(&mut self, rhs: &Self) {
+        *self += rhs;
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        self.clone()
+    }
+
+    fn from_bigint(value: &BigInt) -> Option<Self> {
+        Some(value.clone())
+    }
+}
+
+impl SignedCoefficient for BigInt {
+    fn neg_ref(&self) -> Self {
+        -self
+    }
+}
+
+impl Coefficient for BigUint {
+    fn add_ref(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    fn mul_ref(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+
+    fn add_assign_ref(&mut self, rhs: &Self) {
+        *self += rhs;
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        BigInt::from(self.clone())
+    }
+
+    fn from_bigint(value: &BigInt) -> Option<Self> {
+        value.to_biguint()
+    }
+}
+
+/// Implements [`Coefficient`] for a primitive integer type.
+macro_rules! impl_primitive_coefficient {
+    ($($t:ty => $to:ident),* $(,)?) => {
+        $(
+            impl Coefficient for $t {
+                fn add_ref(&self, rhs: &Self) -> Self {
+                    self + rhs
+                }
+
+                fn mul_ref(&self, rhs: &Self) -> Self {
+                    self * rhs
+                }
+
+                fn to_bigint(&self) -> BigInt {
+                    BigInt::from(*self)
+                }
+
+                fn from_bigint(value: &BigInt) -> Option<Self> {
+                    value.$to()
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive_coefficient!(i64 => to_i64, u64 => to_u64, i128 => to_i128);
+
+impl SignedCoefficient for i64 {
+    fn neg_ref(&self) -> Self {
+        -self
+    }
+}
+
+impl SignedCoefficient for i128 {
+    fn neg_ref(&self) -> Self {
+        -self
+    }
+}
+
+/// An element of `Z_q` stored in Montgomery form, for an odd modulus `q < 2^62`.
+///
+/// The value `a` is stored as `a * 2^64 mod q`, so that multiplication needs a single
+/// Montgomery reduction instead of a division. The modulus is a const generic parameter so that
+/// `zero()` and `one()` are available without a runtime context.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Montgomery<const Q: u64> {
+    /// The value in Montgomery form, in `[0, q)`.
+    value: u64,
+}
+
+impl<const Q: u64> Montgomery<Q> {
+    /// The reduction constants of `q`, after a compile-time check that it is odd and below
+    /// `2^MAX_MODULUS_BITS`, the same bound as [`Zq::new`].
+    const ZQ: Zq = {
+        assert!(
+            Q % 2 == 1 && Q > 1 && Q >> MAX_MODULUS_BITS == 0,
+            "Montgomery modulus must be odd, greater than 1 and below 2^62"
+        );
+        Zq::with_constants(Q)
+    };
+
+    /// Returns the modulus `q`.
+    pub const fn modulus() -> u64 {
+        Q
+    }
+
+    /// Creates an element from an integer, reducing it modulo `q`.
+    pub fn new(value: u64) -> Self {
+        Self {
+            value: Self::ZQ.to_montgomery(value % Q),
+        }
+    }
+
+    /// Returns the canonical representative of the element in `[0, q)`.
+    pub fn value(&self) -> u64 {
+        Self::ZQ.from_montgomery(self.value)
+    }
+}
+
+impl<const Q: u64> fmt::Debug for Montgomery<Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {Q})", self.value())
+    }
+}
+
+impl<const Q: u64> Add for Montgomery<Q> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            value: Self::ZQ.add(self.value, rhs.value),
+        }
+    }
+}
+
+impl<const Q: u64> Sub for Montgomery<Q> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            value: Self::ZQ.sub(self.value, rhs.value),
+        }
+    }
+}
+
+impl<const Q: u64> Neg for Montgomery<Q> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            value: Self::ZQ.neg(self.value),
+        }
+    }
+}
+
+impl<const Q: u64> Mul for Montgomery<Q> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            value: Self::ZQ.mul_montgomery(self.value, rhs.value),
+        }
+    }
+}
+
+impl<const Q: u64> Zero for Montgomery<Q> {
+    fn zero() -> Self {
+        Self { value: 0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const Q: u64> One for Montgomery<Q> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const Q: u64> Coefficient for Montgomery<Q> {
+    fn add_ref(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+
+    fn mul_ref(&self, rhs: &Self) -> Self {
+        *self * *rhs
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        BigInt::from(self.value())
+    }
+
+    fn from_bigint(value: &BigInt) -> Option<Self> {
+        value.mod_floor(&BigInt::from(Q)).to_u64().map(Self::new)
+    }
+}
+
+impl<const Q: u64> SignedCoefficient for Montgomery<Q> {
+    fn neg_ref(&self) -> Self {
+        -*self
+    }
+}
@@ -0,0 +1,324 @@
+//! Constant-time arithmetic for secret polynomials.
+//!
+//! `BigInt` arithmetic takes time that depends on the values involved, so [`Polynomial`] must
+//! not be used for secret keys or errors when timing is observable. This module provides an
+//! opt-in alternative built on the fixed-limb types of [`crate::fixed`]:
+//!
+//! - `subtle` comparisons and conditional selection for [`FixedUint`].
+//! - Branch-free modular operations on [`FixedModulus`] (`ct_add`, `ct_sub`, `ct_neg`,
+//!   `ct_mul`), centering and centered range checks.
+//! - [`CtPolynomial`], a polynomial whose arithmetic only uses these operations.
+//!
+//! Only the values of coefficients are protected: moduli, degrees and lengths are treated as
+//! public. Conversions from and to [`Polynomial`] are variable-time and intended for public data;
+//! secrets should enter through [`CtPolynomial::from_centered`].
+
+use crate::errors::PolynomialError;
+use crate::fixed::{FixedModulus, FixedUint};
+use crate::{GenericPolynomial, Polynomial};
+use num_bigint::{BigInt, Sign};
+use std::fmt;
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
+
+impl<const L: usize> ConstantTimeEq for FixedUint<L> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.limbs[..].ct_eq(&other.limbs[..])
+    }
+}
+
+impl<const L: usize> ConditionallySelectable for FixedUint<L> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut limbs = [0; L];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = u64::conditional_select(&a.limbs[i], &b.limbs[i], choice);
+        }
+        FixedUint::from_limbs(limbs)
+    }
+}
+
+impl<const L: usize> ConstantTimeGreater for FixedUint<L> {
+    fn ct_gt(&self, other: &Self) -> Choice {
+        // self > other exactly when other - self borrows.
+        Choice::from(other.overflowing_sub(self).1 as u8)
+    }
+}
+
+impl<const L: usize> ConstantTimeLess for FixedUint<L> {}
+
+/// A centered residue `(-1)^is_negative * magnitude`, with `magnitude <= (q-1)/2`.
+///
+/// The `Debug` output omits both fields, as for [`CtPolynomial`].
+#[derive(Clone, Copy)]
+pub struct CenteredResidue<const L: usize> {
+    /// The absolute value of the centered representative.
+    pub magnitude: FixedUint<L>,
+    /// Whether the centered representative is negative.
+    pub is_negative: Choice,
+}
+
+impl<const L: usize> CenteredResidue<L> {
+    /// Converts to a `BigInt`. This is variable-time and intended for public values.
+    pub fn to_bigint(&self) -> BigInt {
+        let sign = if bool::from(self.is_negative) {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        BigInt::from_biguint(sign, self.magnitude.to_biguint())
+    }
+}
+
+impl<const L: usize> fmt::Debug for CenteredResidue<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CenteredResidue").finish_non_exhaustive()
+    }
+}
+
+impl<const L: usize> FixedModulus<L> {
+    /// Returns `a + b mod q` for residues `a, b` in constant time.
+    pub fn ct_add(&self, a: &FixedUint<L>, b: &FixedUint<L>) -> FixedUint<L> {
+        let (sum, carry) = a.overflowing_add(b);
+        let (reduced, borrow) = sum.overflowing_sub(&self.q);
+        FixedUint::conditional_select(&sum, &reduced, Choice::from((carry | !borrow) as u8))
+    }
+
+    /// Returns `a - b mod q` for residues `a, b` in constant time.
+    pub fn ct_sub(&self, a: &FixedUint<L>, b: &FixedUint<L>) -> FixedUint<L> {
+        let (diff, borrow) = a.overflowing_sub(b);
+        let (wrapped, _) = diff.overflowing_add(&self.q);
+        FixedUint::conditional_select(&diff, &wrapped, Choice::from(borrow as u8))
+    }
+
+    /// Returns `-a mod q` for a residue `a` in constant time.
+    pub fn ct_neg(&self, a: &FixedUint<L>) -> FixedUint<L> {
+        let (negated, _) = self.q.overflowing_sub(a);
+        FixedUint::conditional_select(&negated, &FixedUint::ZERO, a.ct_eq(&FixedUint::ZERO))
+    }
+
+    /// Returns `a * b * R^-1 mod q` for residues `a, b` in constant time.
+    ///
+    /// This is [`FixedModulus::mul_montgomery`], which is branch-free.
+    pub fn ct_mul_montgomery(&self, a: &FixedUint<L>, b: &FixedUint<L>) -> FixedUint<L> {
+        self.mul_montgomery(a, b)
+    }
+
+    /// Returns `a * b mod q` for residues `a, b` in constant time.
+    ///
+    /// This is [`FixedModulus::mul`], which is branch-free.
+    pub fn ct_mul(&self, a: &FixedUint<L>, b: &FixedUint<L>) -> FixedUint<L> {
+        self.mul(a, b)
+    }
+
+    /// Returns the centered representative of a residue in constant time.
+    pub fn ct_center(&self, a: &FixedUint<L>) -> CenteredResidue<L> {
+        let is_negative = a.ct_gt(&self.half);
+        let (negated, _) = self.q.overflowing_sub(a);
+        CenteredResidue {
+            magnitude: FixedUint::conditional_select(a, &negated, is_negative),
+            is_negative,
+        }
+    }
+
+    /// Checks in constant time that the centered representative of `a` lies in
+    /// `[-bound, bound]`.
+    pub fn ct_in_centered_range(&self, a: &FixedUint<L>, bound: &FixedUint<L>) -> Choice {
+        !self.ct_center(a).magnitude.ct_gt(bound)
+    }
+
+    /// Reduces a signed word `value` with `|value| < q` in constant time.
+    fn ct_from_i64(&self, value: i64) -> FixedUint<L> {
+        let magnitude = FixedUint::from_u64(value.unsigned_abs());
+        debug_assert!(magnitude < self.q, "|value| must be below the modulus");
+        let is_negative = Choice::from((value as u64 >> 63) as u8);
+        let (negated, _) = self.q.overflowing_sub(&magnitude);
+        FixedUint::conditional_select(&magnitude, &negated, is_negative)
+    }
+}
+
+/// A polynomial with secret coefficients modulo a multi-precision `q`.
+///
+/// All arithmetic runs in time independent of the coefficient values. Coefficients are stored as
+/// residues in `[0, q)` in descending order of degree. `Debug` shows only the modulus and the
+/// number of coefficients.
+#[derive(Clone)]
+pub struct CtPolynomial<const L: usize> {
+    /// The coefficient modulus.
+    modulus: FixedModulus<L>,
+    /// Residues in `[0, q)`, highest degree first.
+    residues: GenericPolynomial<FixedUint<L>>,
+}
+
+impl<const L: usize> CtPolynomial<L> {
+    /// Wraps residues that are already reduced modulo `q`.
+    fn from_residues(residues: GenericPolynomial<FixedUint<L>>, modulus: FixedModulus<L>) -> Self {
+        Self { modulus, residues }
+    }
+
+    /// Creates a polynomial from small signed coefficients in descending order, in constant time.
+    ///
+    /// This is the intended entry point for secrets such as ternary keys and bounded errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `coefficients` - Coefficients in descending order of degree, each with `|c| < q`.
+    /// * `modulus` - The coefficient modulus.
+    pub fn from_centered(coefficients: &[i64], modulus: FixedModulus<L>) -> Self {
+        Self::from_residues(
+            GenericPolynomial::new(
+                coefficients
+                    .iter()
+                    .map(|&c| modulus.ct_from_i64(c))
+                    .collect(),
+            ),
+            modulus,
+        )
+    }
+
+    /// Reduces a `BigInt` polynomial modulo `q`.
+    ///
+    /// This conversion is variable-time and should only be used for public polynomials.
+    pub fn from_polynomial(poly: &Polynomial, modulus: FixedModulus<L>) -> Self {
+        Self::from_residues(poly.map(|c| modulus.reduce_bigint(c)), modulus)
+    }
+
+    /// Converts to a `BigInt` polynomial with centered coefficients.
+    ///
+    /// This conversion is variable-time and should only be used once the value is public.
+    pub fn to_polynomial(&self) -> Polynomial {
+        self.residues.map(|c| self.modulus.ct_center(c).to_bigint())
+    }
+
+    /// Returns the coefficient modulus.
+    pub fn modulus(&self) -> &FixedModulus<L> {
+        &self.modulus
+    }
+
+    /// Returns the residues in descending order of degree.
+    pub fn coefficients(&self) -> &[FixedUint<L>] {
+        self.residues.coefficients()
+    }
+
+    /// Returns the centered coefficients in descending order, as computed by a branch-free
+    /// `reduce_and_center`.
+    pub fn centered_coefficients(&self) -> Vec<CenteredResidue<L>> {
+        self.coefficients()
+            .iter()
+            .map(|c| self.modulus.ct_center(c))
+            .collect()
+    }
+
+    /// Checks in constant time that every centered coefficient lies in `[-bound, bound]`.
+    ///
+    /// The result is a `Choice` so that callers can combine it with other secret conditions
+    /// before revealing it.
+    pub fn check_range_centered(&self, bound: &FixedUint<L>) -> Choice {
+        self.coefficients().iter().fold(Choice::from(1), |acc, c| {
+            acc & self.modulus.ct_in_centered_range(c, bound)
+        })
+    }
+
+    /// Checks that two polynomials share a modulus.
+    fn check_modulus(&self, other: &Self) -> Result<(), PolynomialError> {
+        if self.modulus != other.modulus {
+            return Err(PolynomialError::ModulusError {
+                message: format!(
+                    "Moduli {} and {} do not match",
+                    self.modulus.modulus(),
+                    other.modulus.modulus()
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that two polynomials share a modulus and length.
+    fn check_compatible(&self, other: &Self) -> Result<(), PolynomialError> {
+        self.check_modulus(other)?;
+        let (len, other_len) = (self.coefficients().len(), other.coefficients().len());
+        if len != other_len {
+            return Err(PolynomialError::InvalidPolynomial {
+                message: format!("Polynomials have {len} and {other_len} coefficients"),
+            });
+        }
+        Ok(())
+    }
+
+    /// Adds two polynomials with the same number of coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if the moduli differ, and
+    /// `PolynomialError::InvalidPolynomial` if the lengths differ.
+    pub fn add(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.check_compatible(other)?;
+        Ok(Self::from_residues(
+            self.residues
+                .zip_with(&other.residues, &FixedUint::ZERO, |a, b| {
+                    self.modulus.ct_add(a, b)
+                }),
+            self.modulus,
+        ))
+    }
+
+    /// Subtracts `other` from `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if the moduli differ, and
+    /// `PolynomialError::InvalidPolynomial` if the lengths differ.
+    pub fn sub(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.check_compatible(other)?;
+        Ok(Self::from_residues(
+            self.residues
+                .zip_with(&other.residues, &FixedUint::ZERO, |a, b| {
+                    self.modulus.ct_sub(a, b)
+                }),
+            self.modulus,
+        ))
+    }
+
+    /// Negates all coefficients of the polynomial.
+    pub fn neg(&self) -> Self {
+        Self::from_residues(self.residues.map(|c| self.modulus.ct_neg(c)), self.modulus)
+    }
+
+    /// Multiplies two polynomials using the naive algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if the moduli differ.
+    pub fn mul(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.check_modulus(other)?;
+        let m = &self.modulus;
+        Ok(Self::from_residues(
+            m.mul_residues(&self.residues, &other.residues, |a, b| m.ct_add(a, b)),
+            self.modulus,
+        ))
+    }
+}
+
+impl<const L: usize> fmt::Debug for CtPolynomial<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CtPolynomial")
+            .field("modulus", self.modulus.modulus())
+            .field("len", &self.coefficients().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<const L: usize> ConstantTimeEq for CtPolynomial<L> {
+    /// Compares the coefficients in constant time. Polynomials of different lengths or moduli
+    /// are never equal.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        if self.modulus != other.modulus || self.coefficients().len() != other.coefficients().len()
+        {
+            return Choice::from(0);
+        }
+        self.coefficients()
+            .iter()
+            .zip(other.coefficients())
+            .fold(Choice::from(1), |acc, (a, b)| acc & a.ct_eq(b))
+    }
+}
@@ -0,0 +1,355 @@
+//! Coefficient decompositions for range proofs.
+//!
+//! To prove that every coefficient `c` of a polynomial satisfies `|c| <= B`, circuits shift the
+//! coefficient into `[0, 2B]` and decompose `c + B` into bits or limbs. The functions in this
+//! module perform this shift and decomposition, and recompose the original polynomial.
+//!
+//! It also provides [`GadgetDecomposition`], the base-`B` decomposition of ring elements used
+//! for relinearization and key switching.
+//!
+//! Decompositions are returned per coefficient, in the polynomial's storage order (highest
+//! degree first), with bits and limbs in little-endian order (least significant first).
+
+use crate::Polynomial;
+use crate::errors::PolynomialError;
+use crate::utils::{check_range_centered, reduce_and_center, reduce_coefficients};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+
+/// Returns the number of bits needed to represent every value in `[0, 2 * bound]`.
+///
+/// # Arguments
+///
+/// * `bound` - The non-negative bound `B` on the absolute value of coefficients.
+pub fn bit_width_for_bound(bound: &BigInt) -> usize {
+    std::cmp::max((bound * 2u32).bits(), 1) as usize
+}
+
+/// Shifts every coefficient into `[0, 2 * bound]`, checking that it fits.
+fn shifted_coefficients(poly: &Polynomial, bound: &BigInt) -> Result<Vec<BigInt>, PolynomialError> {
+    if bound < &BigInt::zero() {
+        return Err(PolynomialError::RangeCheckError {
+            message: format!("Bound {bound} cannot be negative"),
+        });
+    }
+    check_range_centered(poly.coefficients(), &-bound, bound)?;
+    Ok(poly.coefficients().iter().map(|c| c + bound).collect())
+}
+
+/// Decomposes each coefficient of a polynomial into bits after shifting it by `bound`.
+///
+/// # Arguments
+///
+/// * `poly` - The polynomial to decompose.
+/// * `bound` - The bound `B`; every coefficient must lie in `[-B, B]`.
+///
+/// # Returns
+///
+/// For each coefficient `c`, the [`bit_width_for_bound`] little-endian bits of `c + B`.
+///
+/// # Errors
+///
+/// Returns `PolynomialError::RangeCheckError` if `bound` is negative, or
+/// `PolynomialError::RangeViolations` if a coefficient lies outside `[-B, B]`.
+pub fn decompose_bits(
+    poly: &Polynomial,
+    bound: &BigInt,
+) -> Result<Vec<Vec<bool>>, PolynomialError> {
+    let width = bit_width_for_bound(bound);
+    Ok(shifted_coefficients(poly, bound)?
+        .iter()
+        .map(|c| (0..width).map(|i| c.bit(i as u64)).collect())
+        .collect())
+}
+
+/// Recomposes a polynomial from the output of [`decompose_bits`].
+///
+/// # Arguments
+///
+/// * `bits` - The little-endian bits of each shifted coefficient.
+/// * `bound` - The bound `B` used for the decomposition.
+///
+/// # Returns
+///
+/// The polynomial with coefficients `sum(bit_i * 2^i) - B`.
+pub fn recompose_bits(bits: &[Vec<bool>], bound: &BigInt) -> Polynomial {
+    Polynomial::new(
+        bits.iter()
+            .map(|coeff_bits| {
+                let shifted = coeff_bits.iter().rev().fold(BigInt::zero(), |acc, &bit| {
+                    (acc << 1u32) + if bit { BigInt::one() } else { BigInt::zero() }
+                });
+                shifted - bound
+            })
+            .collect(),
+    )
+}
+
+/// Decomposes each coefficient of a polynomial into limbs after shifting it by `bound`.
+///
+/// # Arguments
+///
+/// * `poly` - The polynomial to decompose.
+/// * `bound` - The bound `B`; every coefficient must lie in `[-B, B]`.
+/// * `limb_bits` - The number of bits per limb; must be positive.
+///
+/// # Returns
+///
+/// For each coefficient `c`, the little-endian limbs of `c + B`, each in `[0, 2^limb_bits)`.
+/// Every coefficient has `ceil(bit_width_for_bound(B) / limb_bits)` limbs.
+///
+/// # Errors
+///
+/// Returns `PolynomialError::RangeCheckError` if `bound` is negative, or
+/// `PolynomialError::RangeViolations` if a coefficient lies outside `[-B, B]`.
+/// Returns `PolynomialError::InvalidPolynomial` if `limb_bits` is zero.
+pub fn decompose_limbs(
+    poly: &Polynomial,
+    bound: &BigInt,
+    limb_bits: usize,
+) -> Result<Vec<Vec<BigInt>>, PolynomialError> {
+    if limb_bits == 0 {
+        return Err(PolynomialError::InvalidPolynomial {
+            message: "Limb size must be positive".to_string(),
+        });
+    }
+
+    let num_limbs = bit_width_for_bound(bound).div_ceil(limb_bits);
+    let mask = (BigInt::one() << limb_bits) - 1;
+    Ok(shifted_coefficients(poly, bound)?
+        .into_iter()
+        .map(|c| {
+            (0..num_limbs)
+                .map(|i| (&c >> (i * limb_bits)) & &mask)
+                .collect()
+        })
+        .collect())
+}
+
+/// Recomposes a polynomial from the output of [`decompose_limbs`].
+///
+/// # Arguments
+///
+/// * `limbs` - The little-endian limbs of each shifted coefficient.
+/// * `bound` - The bound `B` used for the decomposition.
+/// * `limb_bits` - The number of bits per limb.
+///
+/// # Returns
+///
+/// The polynomial with coefficients `sum(limb_i * 2^(i * limb_bits)) - B`.
+pub fn recompose_limbs(limbs: &[Vec<BigInt>], bound: &BigInt, limb_bits: usize) -> Polynomial {
+    Polynomial::new(
+        limbs
+            .iter()
+            .map(|coeff_limbs| {
+                let shifted = coeff_limbs
+                    .iter()
+                    .rev()
+                    .fold(BigInt::zero(), |acc, limb| (acc << limb_bits) + limb);
+                shifted - bound
+            })
+            .collect(),
+    )
+}
+
+/// Base-`B` gadget decomposition of ring elements, as used in key switching.
+///
+/// A ring element `a` modulo `q` is decomposed into digit polynomials `d_0, ..., d_{k-1}` such
+/// that `a ≡ sum(d_j * B^j) (mod q)`, where every coefficient of `d_j` is small. Digits are
+/// either unsigned, in `[0, B)`, or balanced, in the centered range used by
+/// [`crate::utils::reduce_and_center`] modulo `B`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GadgetDecomposition {
+    /// The digit base `B`.
+    base: BigInt,
+    /// The number of digits `k`.
+    num_digits: usize,
+    /// Whether digits are balanced (signed) rather than unsigned.
+    balanced: bool,
+}
+
+impl GadgetDecomposition {
+    /// Creates a gadget decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The digit base `B`; must be at least 2.
+    /// * `num_digits` - The number of digits `k`; must be positive.
+    /// * `balanced` - Whether to produce balanced (signed) digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if `base` or `num_digits` is invalid.
+    pub fn new(base: BigInt, num_digits: usize, balanced: bool) -> Result<Self, PolynomialError> {
+        if base < BigInt::from(2) {
+            return Err(PolynomialError::ModulusError {
+                message: format!("Gadget base {base} must be at least 2"),
+            });
+        }
+        if num_digits == 0 {
+            return Err(PolynomialError::ModulusError {
+                message: "Gadget decomposition needs at least one digit".to_string(),
+            });
+        }
+        Ok(Self {
+            base,
+            num_digits,
+            balanced,
+        })
+    }
+
+    /// Returns the digit base `B`.
+    pub fn base(&self) -> &BigInt {
+        &self.base
+    }
+
+    /// Returns the number of digits `k`.
+    pub fn num_digits(&self) -> usize {
+        self.num_digits
+    }
+
+    /// Returns whether digits are balanced.
+    pub fn is_balanced(&self) -> bool {
+        self.balanced
+    }
+
+    /// Returns the gadget vector `(1, B, ..., B^{k-1})` reduced and centered modulo `modulus`.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - The modulus `q`.
+    pub fn gadget_vector(&self, modulus: &BigInt) -> Vec<BigInt> {
+        let half_modulus = modulus / 2;
+        let mut power = BigInt::one();
+        (0..self.num_digits)
+            .map(|_| {
+                let entry = reduce_and_center(&power, modulus, &half_modulus);
+                power *= &self.base;
+                entry
+            })
+            .collect()
+    }
+
+    /// Decomposes a polynomial modulo `modulus` into digit polynomials.
+    ///
+    /// Coefficients are first reduced modulo `q`: to `[0, q)` for unsigned digits and to the
+    /// centered range for balanced digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - The polynomial to decompose.
+    /// * `modulus` - The modulus `q`.
+    ///
+    /// # Returns
+    ///
+    /// The `k` digit polynomials, least significant first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ArithmeticError` if a coefficient does not fit in `k` digits.
+    pub fn decompose(
+        &self,
+        poly: &Polynomial,
+        modulus: &BigInt,
+    ) -> Result<Vec<Polynomial>, PolynomialError> {
+        let half_base = &self.base / 2;
+        let mut remaining = if self.balanced {
+            poly.reduce_and_center(modulus).coefficients
+        } else {
+            reduce_coefficients(&poly.reduce_and_center(modulus).coefficients, modulus)
+        };
+
+        let mut digits = Vec::with_capacity(self.num_digits);
+        for _ in 0..self.num_digits {
+            let digit: Vec<BigInt> = remaining
+                .iter()
+                .map(|x| {
+                    if self.balanced {
+                        reduce_and_center(x, &self.base, &half_base)
+                    } else {
+                        x % &self.base
+                    }
+                })
+                .collect();
+            remaining
+                .iter_mut()
+                .zip(&digit)
+                .for_each(|(x, d)| *x = (&*x - d) / &self.base);
+            digits.push(Polynomial::new(digit));
+        }
+
+        if let Some(index) = remaining.iter().position(|x| !x.is_zero()) {
+            return Err(PolynomialError::ArithmeticError {
+                message: format!(
+                    "Coefficient {index} does not fit in {} digits of base {}",
+                    self.num_digits, self.base
+                ),
+            });
+        }
+
+        Ok(digits)
+    }
+
+    /// Recomposes digit polynomials into a polynomial reduced and centered modulo `modulus`.
+    ///
+    /// # Arguments
+    ///
+    /// * `digits` - The digit polynomials, least significant first.
+    /// * `modulus` - The modulus `q`.
+    ///
+    /// # Returns
+    ///
+    /// `sum(d_j * B^j)` reduced and centered modulo `q`.
+    pub fn recompose(&self, digits: &[Polynomial], modulus: &BigInt) -> Polynomial {
+        let mut power = BigInt::one();
+        let mut sum = Polynomial::new(vec![]);
+        for digit in digits {
+            sum = sum.add(&digit.scalar_mul(&power));
+            power *= &self.base;
+        }
+        sum.reduce_and_center(modulus)
+    }
+
+    /// Decomposes a polynomial independently modulo each limb of an RNS basis.
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - The polynomial to decompose.
+    /// * `moduli` - The RNS limbs `q_0, ..., q_{L-1}`.
+    ///
+    /// # Returns
+    ///
+    /// For each limb, the `k` digit polynomials of `poly mod q_i`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ArithmeticError` if a residue does not fit in `k` digits.
+    pub fn decompose_rns(
+        &self,
+        poly: &Polynomial,
+        moduli: &[BigInt],
+    ) -> Result<Vec<Vec<Polynomial>>, PolynomialError> {
+        moduli
+            .iter()
+            .map(|modulus| self.decompose(poly, modulus))
+            .collect()
+    }
+
+    /// Recomposes per-limb digit polynomials produced by [`Self::decompose_rns`].
+    ///
+    /// # Arguments
+    ///
+    /// * `digits` - For each limb, the digit polynomials, least significant first.
+    /// * `moduli` - The RNS limbs `q_0, ..., q_{L-1}`.
+    ///
+    /// # Returns
+    ///
+    /// For each limb, the recomposed polynomial reduced and centered modulo `q_i`.
+    pub fn recompose_rns(&self, digits: &[Vec<Polynomial>], moduli: &[BigInt]) -> Vec<Polynomial> {
+        digits
+            .iter()
+            .zip(moduli)
+            .map(|(limb_digits, modulus)| self.recompose(limb_digits, modulus))
+            .collect()
+    }
+}
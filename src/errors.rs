@@ -1,7 +1,49 @@
 //! Error types for polynomial operations.
 
+use num_bigint::BigInt;
+use std::fmt;
 use thiserror::Error;
 
+/// A coefficient that failed a range check.
+///
+/// When `modulus` is `None` the accepted range is `[lower_bound, upper_bound]`. When it is
+/// `Some(q)` the check was a standard (wrapped) check and the accepted set is
+/// `[0, upper_bound] ∪ [q + lower_bound, q)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeViolation {
+    /// Index of the offending coefficient in the checked slice.
+    pub index: usize,
+    /// Value of the offending coefficient.
+    pub value: BigInt,
+    /// Lower bound of the check (negative for standard checks).
+    pub lower_bound: BigInt,
+    /// Upper bound of the check.
+    pub upper_bound: BigInt,
+    /// Modulus used for wraparound in standard checks.
+    pub modulus: Option<BigInt>,
+}
+
+impl fmt::Display for RangeViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.modulus {
+            None => write!(
+                f,
+                "coefficient {} = {} is outside [{}, {}]",
+                self.index, self.value, self.lower_bound, self.upper_bound
+            ),
+            Some(modulus) => write!(
+                f,
+                "coefficient {} = {} is outside [0, {}] ∪ [{}, {})",
+                self.index,
+                self.value,
+                self.upper_bound,
+                modulus + &self.lower_bound,
+                modulus
+            ),
+        }
+    }
+}
+
 /// Errors that can occur during polynomial operations.
 #[derive(Debug, Error)]
 pub enum PolynomialError {
@@ -25,10 +67,25 @@ pub enum PolynomialError {
     #[error("Range check error: {message}")]
     RangeCheckError { message: String },
 
+    /// Range check failure with the offending coefficients and their bounds
+    #[error("Range check error: {message}")]
+    RangeViolations {
+        message: String,
+        violations: Vec<RangeViolation>,
+    },
+
     /// Arithmetic overflow or underflow
     #[error("Arithmetic error: {message}")]
     ArithmeticError { message: String },
 
+    /// Witness export error
+    #[error("Export error: {message}")]
+    ExportError { message: String },
+
+    /// Mismatched dimensions of polynomial vectors or matrices
+    #[error("Dimension error: {message}")]
+    DimensionError { message: String },
+
     /// I/O error
     #[error("I/O error: {0}")]
     IoError(#[from] std::io::Error),
@@ -36,4 +93,8 @@ pub enum PolynomialError {
     /// Parse error for BigInt
     #[error("Parse error: {0}")]
     ParseError(#[from] num_bigint::ParseBigIntError),
+
+    /// Malformed polynomial string, with the byte offset of the problem
+    #[error("Syntax error at position {position}: {message}")]
+    SyntaxError { position: usize, message: String },
 }
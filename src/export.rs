@@ -0,0 +1,213 @@
+//! Export of named polynomials as circuit inputs.
+//!
+//! Circuits consume polynomials as arrays of field elements. This module serializes sets of
+//! named [`Polynomial`]s to the input formats of common proving toolchains:
+//!
+//! - [`WitnessFormat::NoirProverToml`]: a Noir `Prover.toml` file, one array per polynomial.
+//! - [`WitnessFormat::CircomInput`]: a Circom `input.json` file, one array per signal.
+//! - [`WitnessFormat::Json`]: a generic JSON document that also records the coefficient order.
+//!
+//! All coefficients are written as decimal strings so that values larger than 64 bits survive
+//! any JSON or TOML parser.
+
+use crate::Polynomial;
+use crate::errors::PolynomialError;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::Zero;
+use std::collections::HashSet;
+use std::io::Write;
+
+/// The order in which coefficients are written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CoefficientOrder {
+    /// Highest degree first, as stored by [`Polynomial`].
+    #[default]
+    Descending,
+    /// Lowest degree first.
+    Ascending,
+}
+
+/// The output format of an export.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WitnessFormat {
+    /// Noir `Prover.toml`.
+    NoirProverToml,
+    /// Circom input JSON.
+    CircomInput,
+    /// Generic JSON with coefficient-order metadata.
+    Json,
+}
+
+/// Options controlling how coefficients are written.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExportOptions {
+    /// The order in which coefficients are written.
+    pub order: CoefficientOrder,
+    /// If set, every coefficient is lifted to its canonical representative in
+    /// `[0, field_modulus)`, so negative centered values become field elements. Coefficients
+    /// must lie in `[-field_modulus / 2, field_modulus / 2)`, so that no two distinct values
+    /// share a field element.
+    pub field_modulus: Option<BigInt>,
+    /// If set, every polynomial is padded with zero high-degree coefficients to this length,
+    /// typically the ring degree `N`.
+    pub pad_to: Option<usize>,
+}
+
+/// Exports a set of named polynomials to a string in the given format.
+///
+/// # Arguments
+///
+/// * `witnesses` - The named polynomials, written in the given order.
+/// * `format` - The output format.
+/// * `options` - The coefficient order, field lifting and padding options.
+///
+/// # Returns
+///
+/// The serialized document.
+///
+/// # Errors
+///
+/// Returns `PolynomialError::ExportError` if a name is not a valid identifier, is repeated,
+/// a polynomial is longer than the requested padding, the field modulus is not positive, or a
+/// coefficient lies outside the centered range of the field.
+pub fn export_witnesses(
+    witnesses: &[(&str, &Polynomial)],
+    format: WitnessFormat,
+    options: &ExportOptions,
+) -> Result<String, PolynomialError> {
+    let entries = prepare_entries(witnesses, options)?;
+    let mut out = String::new();
+
+    match format {
+        WitnessFormat::NoirProverToml => {
+            for (name, values) in &entries {
+                out.push_str(&format!("{name} = {}\n", format_array(values)));
+            }
+        }
+        WitnessFormat::CircomInput => {
+            out.push_str("{\n");
+            for (i, (name, values)) in entries.iter().enumerate() {
+                let separator = if i + 1 < entries.len() { "," } else { "" };
+                out.push_str(&format!(
+                    "  \"{name}\": {}{separator}\n",
+                    format_array(values)
+                ));
+            }
+            out.push_str("}\n");
+        }
+        WitnessFormat::Json => {
+            let order = match options.order {
+                CoefficientOrder::Descending => "descending",
+                CoefficientOrder::Ascending => "ascending",
+            };
+            out.push_str("{\n");
+            for (i, (name, values)) in entries.iter().enumerate() {
+                let separator = if i + 1 < entries.len() { "," } else { "" };
+                out.push_str(&format!("  \"{name}\": {{\n"));
+                out.push_str(&format!("    \"order\": \"{order}\",\n"));
+                out.push_str(&format!("    \"coefficients\": {}\n", format_array(values)));
+                out.push_str(&format!("  }}{separator}\n"));
+            }
+            out.push_str("}\n");
+        }
+    }
+
+    Ok(out)
+}
+
+/// Exports a set of named polynomials to a writer in the given format.
+///
+/// See [`export_witnesses`] for the meaning of the arguments.
+///
+/// # Errors
+///
+/// Returns `PolynomialError::ExportError` for invalid inputs and `PolynomialError::IoError`
+/// if writing fails.
+pub fn write_witnesses<W: Write>(
+    writer: &mut W,
+    witnesses: &[(&str, &Polynomial)],
+    format: WitnessFormat,
+    options: &ExportOptions,
+) -> Result<(), PolynomialError> {
+    let document = export_witnesses(witnesses, format, options)?;
+    writer.write_all(document.as_bytes())?;
+    Ok(())
+}
+
+/// Validates names and converts every polynomial to its exported coefficient list.
+fn prepare_entries(
+    witnesses: &[(&str, &Polynomial)],
+    options: &ExportOptions,
+) -> Result<Vec<(String, Vec<BigInt>)>, PolynomialError> {
+    if let Some(modulus) = &options.field_modulus {
+        if modulus <= &BigInt::zero() {
+            return Err(PolynomialError::ExportError {
+                message: "Field modulus must be positive".to_string(),
+            });
+        }
+    }
+
+    let mut seen = HashSet::new();
+    witnesses
+        .iter()
+        .map(|(name, poly)| {
+            if !is_identifier(name) {
+                return Err(PolynomialError::ExportError {
+                    message: format!("Invalid witness name '{name}'"),
+                });
+            }
+            if !seen.insert(*name) {
+                return Err(PolynomialError::ExportError {
+                    message: format!("Duplicate witness name '{name}'"),
+                });
+            }
+
+            let mut values = poly.coefficients().to_vec();
+            if let Some(len) = options.pad_to {
+                if values.len() > len {
+                    return Err(PolynomialError::ExportError {
+                        message: format!(
+                            "Witness '{name}' has {} coefficients, more than the padding length {len}",
+                            values.len()
+                        ),
+                    });
+                }
+                let mut padded = vec![BigInt::zero(); len - values.len()];
+                padded.extend(values);
+                values = padded;
+            }
+            if let Some(modulus) = &options.field_modulus {
+                if let Some(c) = values.iter().find(|&c| {
+                    let doubled: BigInt = c * 2;
+                    doubled >= *modulus || doubled < -modulus
+                }) {
+                    return Err(PolynomialError::ExportError {
+                        message: format!(
+                            "Coefficient {c} of witness '{name}' does not fit in the field of order {modulus}"
+                        ),
+                    });
+                }
+                values.iter_mut().for_each(|c| *c = c.mod_floor(modulus));
+            }
+            if options.order == CoefficientOrder::Ascending {
+                values.reverse();
+            }
+
+            Ok((name.to_string(), values))
+        })
+        .collect()
+}
+
+/// Checks that a name is a valid identifier in Noir, Circom and JSON consumers.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Formats coefficients as an array of quoted decimal strings.
+fn format_array(values: &[BigInt]) -> String {
+    let items: Vec<String> = values.iter().map(|v| format!("\"{v}\"")).collect();
+    format!("[{}]", items.join(", "))
+}
@@ -0,0 +1,199 @@
+//! Conversion of polynomial coefficients to SNARK scalar field elements.
+//!
+//! Witnesses produced by [`Polynomial::reduce_and_center`] have negative coefficients, which must
+//! be mapped to canonical field elements in `[0, p)` before entering a circuit. A coefficient `c`
+//! is representable in a field of prime order `p` if it lies in the centered range
+//! [−(p−1)/2, (p−1)/2], so that decoding recovers it exactly.
+
+use crate::Polynomial;
+use crate::errors::PolynomialError;
+use num_bigint::{BigInt, Sign};
+use num_integer::Integer;
+
+/// Number of bytes in an encoded field element.
+pub const FIELD_ELEMENT_BYTES: usize = 32;
+
+/// The byte order of an encoded field element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+/// The modulus of a prime scalar field that fits in 32 bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldModulus {
+    modulus: BigInt,
+    half_modulus: BigInt,
+}
+
+impl FieldModulus {
+    /// Creates a field modulus without checking that it is prime.
+    ///
+    /// Prefer [`Self::bn254`] or [`Self::bls12_381`]. Encodings are only meaningful in a prime
+    /// field, and primality is the caller's responsibility; only the cheap necessary conditions
+    /// are checked here.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - The field order `p`; must be odd, greater than 2 and fit in 32 bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if the modulus is even or out of range.
+    pub fn new_unchecked(modulus: BigInt) -> Result<Self, PolynomialError> {
+        if modulus <= BigInt::from(2) || modulus.bits() > 8 * FIELD_ELEMENT_BYTES as u64 {
+            return Err(PolynomialError::ModulusError {
+                message: format!("Field modulus {modulus} must be in (2, 2^256)"),
+            });
+        }
+        if modulus.is_even() {
+            return Err(PolynomialError::ModulusError {
+                message: format!("Field modulus {modulus} must be odd"),
+            });
+        }
+        let half_modulus = &modulus / 2;
+        Ok(Self {
+            modulus,
+            half_modulus,
+        })
+    }
+
+    /// Returns the scalar field modulus of the BN254 curve.
+    pub fn bn254() -> Self {
+        Self::from_decimal(
+            "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        )
+    }
+
+    /// Returns the scalar field modulus of the BLS12-381 curve.
+    pub fn bls12_381() -> Self {
+        Self::from_decimal(
+            "52435875175126190479447740508185965837690552500527637822603658699938581184513",
+        )
+    }
+
+    fn from_decimal(digits: &str) -> Self {
+        let modulus = BigInt::parse_bytes(digits.as_bytes(), 10).expect("valid field modulus");
+        Self::new_unchecked(modulus).expect("valid field modulus")
+    }
+
+    /// Returns the field order `p`.
+    pub fn modulus(&self) -> &BigInt {
+        &self.modulus
+    }
+
+    /// Maps an integer to its canonical field element in `[0, p)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if `x` lies outside the centered range
+    /// [−(p−1)/2, (p−1)/2] and would therefore not be recovered by [`Self::from_field`].
+    pub fn to_field(&self, x: &BigInt) -> Result<BigInt, PolynomialError> {
+        if x > &self.half_modulus || x < &-&self.half_modulus {
+            return Err(PolynomialError::ModulusError {
+                message: format!(
+                    "Coefficient {x} does not fit in the field of order {}",
+                    self.modulus
+                ),
+            });
+        }
+        Ok(x.mod_floor(&self.modulus))
+    }
+
+    /// Maps a canonical field element back to its centered integer representative.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if `x` is not in `[0, p)`.
+    pub fn from_field(&self, x: &BigInt) -> Result<BigInt, PolynomialError> {
+        if x.sign() == Sign::Minus || x >= &self.modulus {
+            return Err(PolynomialError::ModulusError {
+                message: format!(
+                    "{x} is not a canonical element of the field of order {}",
+                    self.modulus
+                ),
+            });
+        }
+        if x > &self.half_modulus {
+            Ok(x - &self.modulus)
+        } else {
+            Ok(x.clone())
+        }
+    }
+
+    /// Lifts every coefficient of a polynomial to its canonical field element.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if a coefficient does not fit in the field.
+    pub fn to_field_polynomial(&self, poly: &Polynomial) -> Result<Polynomial, PolynomialError> {
+        let coefficients = poly
+            .coefficients()
+            .iter()
+            .map(|c| self.to_field(c))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Polynomial::new(coefficients))
+    }
+
+    /// Encodes the coefficients of a polynomial as canonical 32-byte field elements.
+    ///
+    /// Coefficients are encoded in the polynomial's storage order (descending degree).
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - The polynomial to encode.
+    /// * `endianness` - The byte order of each encoded element.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if a coefficient does not fit in the field.
+    pub fn encode_polynomial(
+        &self,
+        poly: &Polynomial,
+        endianness: Endianness,
+    ) -> Result<Vec<[u8; FIELD_ELEMENT_BYTES]>, PolynomialError> {
+        poly.coefficients()
+            .iter()
+            .map(|c| {
+                let (_, magnitude) = self.to_field(c)?.to_bytes_le();
+                let mut bytes = [0u8; FIELD_ELEMENT_BYTES];
+                bytes[..magnitude.len()].copy_from_slice(&magnitude);
+                if endianness == Endianness::Big {
+                    bytes.reverse();
+                }
+                Ok(bytes)
+            })
+            .collect()
+    }
+
+    /// Decodes canonical 32-byte field elements into a polynomial with centered coefficients.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - The encoded coefficients, in descending order of degree.
+    /// * `endianness` - The byte order of each encoded element.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if an element is not canonical.
+    pub fn decode_polynomial(
+        &self,
+        elements: &[[u8; FIELD_ELEMENT_BYTES]],
+        endianness: Endianness,
+    ) -> Result<Polynomial, PolynomialError> {
+        let coefficients = elements
+            .iter()
+            .map(|bytes| {
+                let value = match endianness {
+                    Endianness::Little => BigInt::from_bytes_le(Sign::Plus, bytes),
+                    Endianness::Big => BigInt::from_bytes_be(Sign::Plus, bytes),
+                };
+                self.from_field(&value)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Polynomial::new(coefficients))
+    }
+}
@@ -0,0 +1,469 @@
+//! Stack-allocated multi-precision modular arithmetic for moduli of up to `64 * L` bits.
+//!
+//! [`FixedUint<L>`] is an unsigned integer of `L` little-endian 64-bit limbs, and
+//! [`FixedModulus<L>`] an odd modulus with its Montgomery constants. Residues are stored in
+//! plain form in `[0, q)`; multiplication uses CIOS Montgomery multiplication with `R = 2^(64L)`
+//! followed by a multiplication by `R^2 mod q`, so no heap allocation occurs in arithmetic.
+//!
+//! [`FixedPolynomial<L>`] stores the coefficients of a polynomial modulo `q` as residues and
+//! converts to and from [`Polynomial`], whose coefficients are centered as by
+//! [`Polynomial::reduce_and_center`].
+
+use crate::errors::PolynomialError;
+use crate::zq::neg_inverse;
+use crate::{GenericPolynomial, Polynomial};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use subtle::{Choice, ConditionallySelectable};
+
+/// A 128-bit unsigned integer.
+pub type U128 = FixedUint<2>;
+/// A 256-bit unsigned integer.
+pub type U256 = FixedUint<4>;
+/// A 512-bit unsigned integer.
+pub type U512 = FixedUint<8>;
+
+/// An unsigned integer of `L` 64-bit limbs, least significant limb first.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedUint<const L: usize> {
+    pub(crate) limbs: [u64; L],
+}
+
+impl<const L: usize> FixedUint<L> {
+    /// The integer zero.
+    pub const ZERO: Self = Self { limbs: [0; L] };
+
+    /// Creates an integer from its limbs, least significant first.
+    pub const fn from_limbs(limbs: [u64; L]) -> Self {
+        Self { limbs }
+    }
+
+    /// Creates an integer from a `u64`.
+    pub fn from_u64(value: u64) -> Self {
+        let mut limbs = [0; L];
+        limbs[0] = value;
+        Self { limbs }
+    }
+
+    /// Returns the limbs, least significant first.
+    pub fn limbs(&self) -> &[u64; L] {
+        &self.limbs
+    }
+
+    /// Returns `true` if the integer is zero.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&l| l == 0)
+    }
+
+    /// Returns the number of significant bits.
+    pub fn bits(&self) -> u32 {
+        match self.limbs.iter().rposition(|&l| l != 0) {
+            Some(i) => 64 * i as u32 + (64 - self.limbs[i].leading_zeros()),
+            None => 0,
+        }
+    }
+
+    /// Converts a `BigUint` to a fixed-limb integer, or returns `None` if it does not fit.
+    pub fn from_biguint(value: &BigUint) -> Option<Self> {
+        let digits = value.to_u64_digits();
+        if digits.len() > L {
+            return None;
+        }
+        let mut limbs = [0; L];
+        limbs[..digits.len()].copy_from_slice(&digits);
+        Some(Self { limbs })
+    }
+
+    /// Converts the integer to a `BigUint`.
+    pub fn to_biguint(&self) -> BigUint {
+        self.limbs
+            .iter()
+            .rev()
+            .fold(BigUint::zero(), |acc, &l| (acc << 64u32) + l)
+    }
+
+    /// Returns `self + rhs` and the carry out of the top limb.
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+        let mut limbs = [0; L];
+        let mut carry = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (s1, c1) = self.limbs[i].overflowing_add(rhs.limbs[i]);
+            let (s2, c2) = s1.overflowing_add(u64::from(carry));
+            *limb = s2;
+            carry = c1 | c2;
+        }
+        (Self { limbs }, carry)
+    }
+
+    /// Returns `self - rhs` and the borrow out of the top limb.
+    pub fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+        let mut limbs = [0; L];
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (d1, b1) = self.limbs[i].overflowing_sub(rhs.limbs[i]);
+            let (d2, b2) = d1.overflowing_sub(u64::from(borrow));
+            *limb = d2;
+            borrow = b1 | b2;
+        }
+        (Self { limbs }, borrow)
+    }
+}
+
+impl<const L: usize> Default for FixedUint<L> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const L: usize> Ord for FixedUint<L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl<const L: usize> PartialOrd for FixedUint<L> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const L: usize> fmt::Debug for FixedUint<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FixedUint<{L}>({})", self.to_biguint())
+    }
+}
+
+impl<const L: usize> fmt::Display for FixedUint<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_biguint())
+    }
+}
+
+/// Returns `a * b + c + carry` as a (low, high) pair of words.
+#[inline]
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = u128::from(a) * u128::from(b) + u128::from(c) + u128::from(carry);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// An odd modulus `q < 2^(64L)` with its Montgomery constants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedModulus<const L: usize> {
+    /// The modulus `q`.
+    pub(crate) q: FixedUint<L>,
+    /// `(q - 1) / 2`, the largest residue with a non-negative centered representative.
+    pub(crate) half: FixedUint<L>,
+    /// The Montgomery constant `-q^{-1} mod 2^64`.
+    neg_q_inv: u64,
+    /// `R^2 mod q` for `R = 2^(64L)`.
+    r2: FixedUint<L>,
+}
+
+impl<const L: usize> FixedModulus<L> {
+    /// Creates a modulus and precomputes its Montgomery constants.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The modulus, which must be odd, greater than 1 and below `2^(64L)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if `q` is out of range or even.
+    pub fn new(q: &BigInt) -> Result<Self, PolynomialError> {
+        let fixed = q
+            .to_biguint()
+            .filter(|q| q > &BigUint::one() && q.is_odd())
+            .and_then(|q| FixedUint::from_biguint(&q))
+            .ok_or_else(|| PolynomialError::ModulusError {
+                message: format!(
+                    "Modulus {q} must be odd, greater than 1 and below 2^{}",
+                    64 * L
+                ),
+            })?;
+
+        let q_big = fixed.to_biguint();
+        let r2 = (BigUint::one() << (128 * L)) % &q_big;
+
+        Ok(Self {
+            q: fixed,
+            half: FixedUint::from_biguint(&(&q_big >> 1u32)).expect("half of q fits"),
+            neg_q_inv: neg_inverse(fixed.limbs[0]),
+            r2: FixedUint::from_biguint(&r2).expect("residue fits"),
+        })
+    }
+
+    /// Returns the modulus `q`.
+    pub fn modulus(&self) -> &FixedUint<L> {
+        &self.q
+    }
+
+    /// Reduces a signed integer of any size modulo `q`.
+    pub fn reduce_bigint(&self, a: &BigInt) -> FixedUint<L> {
+        let q = BigInt::from(self.q.to_biguint());
+        let r = a
+            .mod_floor(&q)
+            .to_biguint()
+            .expect("residue is non-negative");
+        FixedUint::from_biguint(&r).expect("residue fits")
+    }
+
+    /// Returns the centered representative of a residue in `[-(q-1)/2, (q-1)/2]`.
+    pub fn center(&self, a: &FixedUint<L>) -> BigInt {
+        if a > &self.half {
+            BigInt::from_biguint(Sign::Minus, self.q.overflowing_sub(a).0.to_biguint())
+        } else {
+            BigInt::from(a.to_biguint())
+        }
+    }
+
+    /// Returns `a + b mod q` for residues `a, b`.
+    pub fn add(&self, a: &FixedUint<L>, b: &FixedUint<L>) -> FixedUint<L> {
+        let (sum, carry) = a.overflowing_add(b);
+        if carry || sum >= self.q {
+            sum.overflowing_sub(&self.q).0
+        } else {
+            sum
+        }
+    }
+
+    /// Returns `a - b mod q` for residues `a, b`.
+    pub fn sub(&self, a: &FixedUint<L>, b: &FixedUint<L>) -> FixedUint<L> {
+        let (diff, borrow) = a.overflowing_sub(b);
+        if borrow {
+            diff.overflowing_add(&self.q).0
+        } else {
+            diff
+        }
+    }
+
+    /// Returns `-a mod q` for a residue `a`.
+    pub fn neg(&self, a: &FixedUint<L>) -> FixedUint<L> {
+        if a.is_zero() {
+            *a
+        } else {
+            self.q.overflowing_sub(a).0
+        }
+    }
+
+    /// Returns `a * b * R^-1 mod q` for residues `a, b`, by CIOS Montgomery multiplication.
+    ///
+    /// Neither the loop structure nor the final subtraction depends on the values of `a` and
+    /// `b`, so this is also the multiplication used by the constant-time operations of
+    /// [`crate::ct`].
+    pub fn mul_montgomery(&self, a: &FixedUint<L>, b: &FixedUint<L>) -> FixedUint<L> {
+        let (a, b, q) = (&a.limbs, &b.limbs, &self.q.limbs);
+        let mut t = [0u64; L];
+        let mut t_hi = 0u64;
+        for &b_i in b {
+            let mut carry = 0;
+            for j in 0..L {
+                (t[j], carry) = mac(a[j], b_i, t[j], carry);
+            }
+            let (sum, overflow) = t_hi.overflowing_add(carry);
+            t_hi = sum;
+            let t_top = u64::from(overflow);
+
+            let m = t[0].wrapping_mul(self.neg_q_inv);
+            let (_, mut carry) = mac(m, q[0], t[0], 0);
+            for j in 1..L {
+                (t[j - 1], carry) = mac(m, q[j], t[j], carry);
+            }
+            let (sum, overflow) = t_hi.overflowing_add(carry);
+            t[L - 1] = sum;
+            t_hi = t_top + u64::from(overflow);
+        }
+
+        // Here `t + t_hi * 2^(64L) < 2q`, so one conditional subtraction is enough.
+        let t = FixedUint { limbs: t };
+        let (reduced, borrow) = t.overflowing_sub(&self.q);
+        let overflow = Choice::from(t_hi as u8);
+        FixedUint::conditional_select(&t, &reduced, overflow | !Choice::from(borrow as u8))
+    }
+
+    /// Converts a residue to Montgomery form `a * R mod q`.
+    pub fn to_montgomery(&self, a: &FixedUint<L>) -> FixedUint<L> {
+        self.mul_montgomery(a, &self.r2)
+    }
+
+    /// Returns `a * b mod q` for residues `a, b`.
+    pub fn mul(&self, a: &FixedUint<L>, b: &FixedUint<L>) -> FixedUint<L> {
+        self.mul_montgomery(&self.to_montgomery(a), b)
+    }
+
+    /// Multiplies two residue polynomials using the naive algorithm.
+    ///
+    /// The coefficients of `a` are converted to Montgomery form once, so that each term product
+    /// needs a single Montgomery multiplication. Products are accumulated with `add`, which lets
+    /// [`crate::ct::CtPolynomial`] substitute its constant-time addition.
+    pub(crate) fn mul_residues(
+        &self,
+        a: &GenericPolynomial<FixedUint<L>>,
+        b: &GenericPolynomial<FixedUint<L>>,
+        add: impl Fn(&FixedUint<L>, &FixedUint<L>) -> FixedUint<L>,
+    ) -> GenericPolynomial<FixedUint<L>> {
+        let (a, b) = (a.coefficients(), b.coefficients());
+        if a.is_empty() || b.is_empty() {
+            return GenericPolynomial::new(vec![FixedUint::ZERO]);
+        }
+
+        let mut product = vec![FixedUint::ZERO; a.len() + b.len() - 1];
+        for (i, a) in a.iter().map(|a| self.to_montgomery(a)).enumerate() {
+            for (j, b) in b.iter().enumerate() {
+                product[i + j] = add(&product[i + j], &self.mul_montgomery(&a, b));
+            }
+        }
+        GenericPolynomial::new(product)
+    }
+}
+
+/// A polynomial with coefficients modulo a multi-precision `q`, stored as fixed-limb residues in
+/// descending order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedPolynomial<const L: usize> {
+    /// The coefficient modulus.
+    modulus: FixedModulus<L>,
+    /// Residues in `[0, q)`, highest degree first.
+    residues: GenericPolynomial<FixedUint<L>>,
+}
+
+impl<const L: usize> FixedPolynomial<L> {
+    /// Wraps residues that are already reduced modulo `q`.
+    fn from_residues(residues: GenericPolynomial<FixedUint<L>>, modulus: FixedModulus<L>) -> Self {
+        Self { modulus, residues }
+    }
+
+    /// Creates the zero polynomial of specified degree.
+    pub fn zero(degree: usize, modulus: FixedModulus<L>) -> Self {
+        Self::from_residues(
+            GenericPolynomial::new(vec![FixedUint::ZERO; degree + 1]),
+            modulus,
+        )
+    }
+
+    /// Reduces a `BigInt` polynomial modulo `q`.
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - The polynomial to convert.
+    /// * `modulus` - The coefficient modulus.
+    pub fn from_polynomial(poly: &Polynomial, modulus: FixedModulus<L>) -> Self {
+        Self::from_residues(poly.map(|c| modulus.reduce_bigint(c)), modulus)
+    }
+
+    /// Converts the polynomial to a `BigInt` polynomial with centered coefficients.
+    ///
+    /// For every polynomial `p`, `FixedPolynomial::from_polynomial(p, modulus).to_polynomial()`
+    /// equals `p.reduce_and_center(q)`.
+    pub fn to_polynomial(&self) -> Polynomial {
+        self.residues.map(|c| self.modulus.center(c))
+    }
+
+    /// Returns the coefficient modulus.
+    pub fn modulus(&self) -> &FixedModulus<L> {
+        &self.modulus
+    }
+
+    /// Returns the residues in `[0, q)` as a polynomial over `FixedUint<L>`.
+    pub fn residues(&self) -> &GenericPolynomial<FixedUint<L>> {
+        &self.residues
+    }
+
+    /// Returns the residues in descending order of degree.
+    pub fn coefficients(&self) -> &[FixedUint<L>] {
+        self.residues.coefficients()
+    }
+
+    /// Returns the degree of the polynomial.
+    ///
+    /// The degree of a zero polynomial is 0.
+    pub fn degree(&self) -> usize {
+        self.residues.degree()
+    }
+
+    /// Checks if the polynomial is zero.
+    pub fn is_zero(&self) -> bool {
+        self.coefficients().iter().all(FixedUint::is_zero)
+    }
+
+    /// Checks that two polynomials share a modulus.
+    fn check_modulus(&self, other: &Self) -> Result<(), PolynomialError> {
+        if self.modulus != other.modulus {
+            return Err(PolynomialError::ModulusError {
+                message: format!(
+                    "Moduli {} and {} do not match",
+                    self.modulus.q, other.modulus.q
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Adds two polynomials.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if the moduli differ.
+    pub fn add(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.check_modulus(other)?;
+        Ok(Self::from_residues(
+            self.residues
+                .zip_with(&other.residues, &FixedUint::ZERO, |a, b| {
+                    self.modulus.add(a, b)
+                }),
+            self.modulus,
+        ))
+    }
+
+    /// Subtracts `other` from `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if the moduli differ.
+    pub fn sub(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.check_modulus(other)?;
+        Ok(Self::from_residues(
+            self.residues
+                .zip_with(&other.residues, &FixedUint::ZERO, |a, b| {
+                    self.modulus.sub(a, b)
+                }),
+            self.modulus,
+        ))
+    }
+
+    /// Negates all coefficients of the polynomial.
+    pub fn neg(&self) -> Self {
+        Self::from_residues(self.residues.map(|c| self.modulus.neg(c)), self.modulus)
+    }
+
+    /// Multiplies each coefficient by a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `scalar` - The scalar, reduced modulo `q` before use.
+    pub fn scalar_mul(&self, scalar: &BigInt) -> Self {
+        let w = self
+            .modulus
+            .to_montgomery(&self.modulus.reduce_bigint(scalar));
+        Self::from_residues(
+            self.residues.map(|c| self.modulus.mul_montgomery(c, &w)),
+            self.modulus,
+        )
+    }
+
+    /// Multiplies two polynomials using the naive algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if the moduli differ.
+    pub fn mul(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.check_modulus(other)?;
+        let m = &self.modulus;
+        Ok(Self::from_residues(
+            m.mul_residues(&self.residues, &other.residues, |a, b| m.add(a, b)),
+            self.modulus,
+        ))
+    }
+}
@@ -0,0 +1,96 @@
+//! Galois elements and automorphism helpers for negacyclic rings.
+//!
+//! The Galois group of `Z[x]/(x^N + 1)`, with `N` a power of two, is `(Z/2NZ)^*`: the odd
+//! residues modulo `2N`, acting as `σ_k: x ↦ x^k` (see [`crate::Polynomial::automorphism`]).
+//! It is generated by `5`, of order `N/2`, and `2N - 1`, of order 2. In BFV/BGV batching, `5`
+//! rotates the columns of the plaintext matrix and `2N - 1` swaps its rows; in CKKS they
+//! rotate and conjugate the slots.
+//!
+//! In the NTT domain, where a polynomial is stored as its evaluations at the primitive
+//! `2N`-th roots of unity `ζ^{2j+1}`, the automorphism is a plain index permutation, given by
+//! [`automorphism_ntt_permutation`].
+
+use crate::errors::PolynomialError;
+use crate::utils::check_ring_dimension;
+
+/// The generator of the column-rotation subgroup of the Galois group.
+pub const ROTATION_GENERATOR: usize = 5;
+
+/// Returns the Galois element `5^steps mod 2n` rotating the slots by `steps` positions.
+///
+/// Negative `steps` rotate in the opposite direction.
+///
+/// # Arguments
+///
+/// * `steps` - The number of positions to rotate by.
+/// * `n` - The ring dimension; must be a power of two.
+///
+/// # Errors
+///
+/// Returns `PolynomialError::CyclotomicError` if `n` is invalid.
+pub fn rotation_galois_element(steps: i64, n: usize) -> Result<usize, PolynomialError> {
+    check_ring_dimension(n)?;
+
+    // 5 has order n / 2 modulo 2n.
+    let order = (n / 2) as i64;
+    let exponent = steps.rem_euclid(order.max(1)) as u64;
+    let two_n = 2 * n as u64;
+
+    let mut result = 1u64;
+    let mut base = ROTATION_GENERATOR as u64 % two_n;
+    let mut e = exponent;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = result * base % two_n;
+        }
+        base = base * base % two_n;
+        e >>= 1;
+    }
+
+    Ok(result as usize)
+}
+
+/// Returns the Galois element `2n - 1` that swaps the rows of the plaintext matrix (BFV/BGV)
+/// or conjugates the slots (CKKS).
+///
+/// # Arguments
+///
+/// * `n` - The ring dimension; must be a power of two.
+///
+/// # Errors
+///
+/// Returns `PolynomialError::CyclotomicError` if `n` is invalid.
+pub fn conjugation_galois_element(n: usize) -> Result<usize, PolynomialError> {
+    check_ring_dimension(n)?;
+    Ok(2 * n - 1)
+}
+
+/// Returns the index permutation implementing `σ_k` in the NTT domain.
+///
+/// The NTT values are assumed to be in natural order: entry `j` holds the evaluation at
+/// `ζ^{2j+1}` for a primitive `2n`-th root of unity `ζ`. Since `σ_k(a)(ζ^e) = a(ζ^{ke})`, the
+/// transformed values are `out[j] = values[perm[j]]`, where `2 * perm[j] + 1 ≡ k(2j + 1)`
+/// modulo `2n`.
+///
+/// # Arguments
+///
+/// * `k` - The Galois element; must be odd. It is taken modulo `2n`.
+/// * `n` - The ring dimension; must be a power of two.
+///
+/// # Errors
+///
+/// Returns `PolynomialError::CyclotomicError` if `n` is invalid or `k` is even.
+pub fn automorphism_ntt_permutation(k: usize, n: usize) -> Result<Vec<usize>, PolynomialError> {
+    check_ring_dimension(n)?;
+    if k % 2 == 0 {
+        return Err(PolynomialError::CyclotomicError {
+            message: format!("Galois element {k} must be odd"),
+        });
+    }
+
+    let two_n = 2 * n;
+    let k = k % two_n;
+    Ok((0..n)
+        .map(|j| ((k * (2 * j + 1)) % two_n - 1) / 2)
+        .collect())
+}
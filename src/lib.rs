@@ -6,9 +6,28 @@
 //! ## Features
 //!
 //! - Uses `num-bigint` for coefficient representation.
+//! - Generic Coefficients: Polynomials over `BigUint`, `i64`, `u64`, `i128` or Montgomery-form `Z_q` elements.
 //! - Polynomial Modular Arithmetic: Addition, subtraction, multiplication, division reduction modulo cyclotomic polynomials and prime moduli.
 //! - Range Checking: Utilities for coefficient range validation.
+//! - Decomposition: Bit and limb decompositions for range proofs, and gadget decomposition for key switching.
+//! - Witnesses: Ring reductions that return the quotients needed to prove ring equations over Z.
 //! - Serialization: Optional serde support for polynomial serialization with bincode integration.
+//! - Export: Named polynomials as Noir `Prover.toml`, Circom input JSON or generic JSON.
+//! - Fields: Canonical BN254 and BLS12-381 scalar field encodings of coefficients.
+//! - Galois: Automorphisms `x ↦ x^k` in coefficient and NTT form, rotation elements and trace maps.
+//! - RNS: Residue number system polynomials with fast and exact (BEHZ) base conversion and HPS scale-and-round.
+//! - Sampling: Uniform, ternary and bounded random polynomial samplers.
+//! - Transcripts: Fiat–Shamir transcripts with a canonical polynomial encoding.
+//! - Zq: Word-sized modular arithmetic with Barrett, Montgomery and Shoup multiplication.
+//! - Fixed-Limb: Stack-allocated multi-precision residues and polynomials for 128–512 bit moduli.
+//! - Constant Time: Branch-free modular arithmetic, centering and range checks for secret polynomials.
+//! - Secrets: A `SecretPolynomial` wrapper that zeroizes its coefficients on drop and hides them from `Debug`.
+//! - Sparse: Sparse polynomials with O(h·N) sparse × dense negacyclic multiplication.
+//! - Module Lattices: `PolyVec` and `PolyMatrix` with ring inner products and matrix-vector products.
+//! - Multivariate: Sparse multivariate polynomials with lex and grevlex orders and partial evaluation.
+//! - Rationals: `BigRational` polynomials with exact division, interpolation and denominator clearing.
+//! - Parsing: `FromStr` for the `Display` format and common variants, with error positions.
+//! - BFV: A minimal, non-production reference BFV scheme exposing encryption witnesses.
 //!
 //! ## Mathematical Background
 //!
@@ -19,10 +38,37 @@
 //! - Homomorphic encryption: BFV, BGV, and CKKS schemes.
 //! - Zero-knowledge proofs: Polynomial commitment schemes.
 
+pub mod bfv;
+pub mod coefficient;
+pub mod ct;
+pub mod decomposition;
 pub mod errors;
+pub mod export;
+pub mod field;
+pub mod fixed;
+pub mod galois;
+pub mod multivariate;
+mod parse;
 pub mod polynomial;
+pub mod polyvec;
+pub mod rational;
+pub mod rns;
+pub mod sampling;
+pub mod secret;
+pub mod sparse;
+pub mod transcript;
 pub mod utils;
+pub mod zq;
 
-pub use errors::PolynomialError;
-pub use polynomial::Polynomial;
+pub use coefficient::{Coefficient, Montgomery, SignedCoefficient};
+pub use errors::{PolynomialError, RangeViolation};
+pub use fixed::{FixedModulus, FixedPolynomial, FixedUint};
+pub use multivariate::{MonomialOrder, MultivariatePolynomial};
+pub use parse::MAX_PARSED_DEGREE;
+pub use polynomial::{GenericPolynomial, Polynomial, RingReduction};
+pub use polyvec::{PolyMatrix, PolyVec};
+pub use rational::RationalPolynomial;
+pub use secret::SecretPolynomial;
+pub use sparse::SparsePolynomial;
 pub use utils::*;
+pub use zq::{Zq, ZqPolynomial};
@@ -0,0 +1,452 @@
+//! Sparse multivariate polynomials with `BigInt` coefficients.
+//!
+//! A [`MultivariatePolynomial`] in `num_vars` variables `x0, x1, ...` stores its non-zero terms
+//! as `(exponents, coefficient)` pairs, where `exponents[i]` is the power of `x_i`. Terms are kept
+//! sorted in decreasing [`MonomialOrder`], so the leading term comes first, as the leading
+//! coefficient does in [`Polynomial`].
+
+use crate::Polynomial;
+use crate::errors::PolynomialError;
+use num_bigint::BigInt;
+use num_traits::{One, Pow, Zero};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An exponent vector, one entry per variable.
+pub type Monomial = Vec<usize>;
+
+/// A total order on monomials, used to sort terms and pick the leading term.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MonomialOrder {
+    /// Lexicographic order with `x0 > x1 > ...`.
+    #[default]
+    Lex,
+    /// Graded reverse lexicographic order: higher total degree first, ties broken in favour of
+    /// the smaller exponent in the last variable that differs.
+    GrevLex,
+}
+
+impl MonomialOrder {
+    /// Compares two monomials with the same number of variables.
+    pub fn compare(&self, a: &[usize], b: &[usize]) -> Ordering {
+        match self {
+            MonomialOrder::Lex => a.cmp(b),
+            MonomialOrder::GrevLex => {
+                let degree = |m: &[usize]| m.iter().sum::<usize>();
+                degree(a).cmp(&degree(b)).then_with(|| {
+                    a.iter()
+                        .zip(b)
+                        .rev()
+                        .find(|(x, y)| x != y)
+                        .map_or(Ordering::Equal, |(x, y)| y.cmp(x))
+                })
+            }
+        }
+    }
+}
+
+/// A multivariate polynomial stored as its non-zero terms in decreasing monomial order.
+///
+/// Deserialized terms are checked and normalized as by [`MultivariatePolynomial::new`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "RawMultivariatePolynomial")
+)]
+pub struct MultivariatePolynomial {
+    num_vars: usize,
+    order: MonomialOrder,
+    /// `(exponents, coefficient)` pairs with distinct exponents and non-zero coefficients.
+    terms: Vec<(Monomial, BigInt)>,
+}
+
+/// The serialized form of a [`MultivariatePolynomial`], whose terms may be in any order.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawMultivariatePolynomial {
+    num_vars: usize,
+    order: MonomialOrder,
+    terms: Vec<(Monomial, BigInt)>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawMultivariatePolynomial> for MultivariatePolynomial {
+    type Error = PolynomialError;
+
+    fn try_from(raw: RawMultivariatePolynomial) -> Result<Self, Self::Error> {
+        Self::new(raw.num_vars, raw.order, raw.terms)
+    }
+}
+
+impl MultivariatePolynomial {
+    /// Creates a multivariate polynomial from terms in any order.
+    ///
+    /// Terms with the same exponents are summed and zero terms are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_vars` - The number of variables.
+    /// * `order` - The monomial order used to sort the terms.
+    /// * `terms` - `(exponents, coefficient)` pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if an exponent vector does not have `num_vars`
+    /// entries.
+    pub fn new(
+        num_vars: usize,
+        order: MonomialOrder,
+        terms: Vec<(Monomial, BigInt)>,
+    ) -> Result<Self, PolynomialError> {
+        if let Some((exponents, _)) = terms.iter().find(|(e, _)| e.len() != num_vars) {
+            return Err(PolynomialError::DimensionError {
+                message: format!(
+                    "Monomial has {} exponents, expected {num_vars}",
+                    exponents.len()
+                ),
+            });
+        }
+        Ok(Self::from_terms(num_vars, order, terms))
+    }
+
+    /// Sums, sorts and filters terms whose exponent vectors are known to have `num_vars` entries.
+    fn from_terms(
+        num_vars: usize,
+        order: MonomialOrder,
+        terms: impl IntoIterator<Item = (Monomial, BigInt)>,
+    ) -> Self {
+        let mut merged: BTreeMap<Monomial, BigInt> = BTreeMap::new();
+        for (exponents, coeff) in terms {
+            *merged.entry(exponents).or_default() += coeff;
+        }
+        let mut terms: Vec<_> = merged.into_iter().filter(|(_, c)| !c.is_zero()).collect();
+        terms.sort_by(|(a, _), (b, _)| order.compare(b, a));
+        Self {
+            num_vars,
+            order,
+            terms,
+        }
+    }
+
+    /// Creates the zero polynomial.
+    pub fn zero(num_vars: usize, order: MonomialOrder) -> Self {
+        Self {
+            num_vars,
+            order,
+            terms: Vec::new(),
+        }
+    }
+
+    /// Creates a constant polynomial.
+    pub fn constant(num_vars: usize, order: MonomialOrder, value: BigInt) -> Self {
+        Self::from_terms(num_vars, order, [(vec![0; num_vars], value)])
+    }
+
+    /// Creates the polynomial `x_var`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if `var >= num_vars`.
+    pub fn variable(
+        var: usize,
+        num_vars: usize,
+        order: MonomialOrder,
+    ) -> Result<Self, PolynomialError> {
+        check_variable(var, num_vars)?;
+        let mut exponents = vec![0; num_vars];
+        exponents[var] = 1;
+        Ok(Self::from_terms(
+            num_vars,
+            order,
+            [(exponents, BigInt::one())],
+        ))
+    }
+
+    /// Embeds a univariate polynomial as a polynomial in `x_var`.
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - The univariate polynomial.
+    /// * `var` - The index of the variable that replaces `x`.
+    /// * `num_vars` - The number of variables.
+    /// * `order` - The monomial order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if `var >= num_vars`.
+    pub fn from_polynomial(
+        poly: &Polynomial,
+        var: usize,
+        num_vars: usize,
+        order: MonomialOrder,
+    ) -> Result<Self, PolynomialError> {
+        check_variable(var, num_vars)?;
+        let terms = poly
+            .coefficients()
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(degree, coeff)| {
+                let mut exponents = vec![0; num_vars];
+                exponents[var] = degree;
+                (exponents, coeff.clone())
+            });
+        Ok(Self::from_terms(num_vars, order, terms))
+    }
+
+    /// Converts to a univariate polynomial if at most one variable is present.
+    ///
+    /// # Returns
+    ///
+    /// The polynomial in the single variable that occurs, with `x` in its place.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::InvalidPolynomial` if more than one variable occurs.
+    pub fn to_polynomial(&self) -> Result<Polynomial, PolynomialError> {
+        let present: Vec<usize> = (0..self.num_vars)
+            .filter(|&i| self.terms.iter().any(|(e, _)| e[i] > 0))
+            .collect();
+        if present.len() > 1 {
+            return Err(PolynomialError::InvalidPolynomial {
+                message: format!("Polynomial depends on variables {present:?}"),
+            });
+        }
+        let Some(&var) = present.first() else {
+            let constant = self
+                .terms
+                .first()
+                .map_or_else(BigInt::zero, |(_, c)| c.clone());
+            return Ok(Polynomial::new(vec![constant]));
+        };
+        let mut ascending = vec![BigInt::zero(); self.degree_in(var) + 1];
+        for (exponents, coeff) in &self.terms {
+            ascending[exponents[var]] = coeff.clone();
+        }
+        Ok(Polynomial::from_ascending_coefficients(ascending))
+    }
+
+    /// Returns the number of variables.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns the monomial order of the terms.
+    pub fn order(&self) -> MonomialOrder {
+        self.order
+    }
+
+    /// Returns the non-zero terms in decreasing monomial order.
+    pub fn terms(&self) -> &[(Monomial, BigInt)] {
+        &self.terms
+    }
+
+    /// Returns the leading term, or `None` for the zero polynomial.
+    pub fn leading_term(&self) -> Option<&(Monomial, BigInt)> {
+        self.terms.first()
+    }
+
+    /// Returns the polynomial with its terms sorted by another monomial order.
+    pub fn with_order(&self, order: MonomialOrder) -> Self {
+        Self::from_terms(self.num_vars, order, self.terms.iter().cloned())
+    }
+
+    /// Returns the total degree. The degree of a zero polynomial is 0.
+    pub fn total_degree(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|(e, _)| e.iter().sum())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the degree in `x_var`, or 0 if `var` is out of range.
+    pub fn degree_in(&self, var: usize) -> usize {
+        self.terms
+            .iter()
+            .filter_map(|(e, _)| e.get(var).copied())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Checks if the polynomial is zero.
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Adds two polynomials. The result uses the monomial order of `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if the numbers of variables differ.
+    pub fn add(&self, other: &Self) -> Result<Self, PolynomialError> {
+        check_num_vars(self.num_vars, other.num_vars)?;
+        Ok(Self::from_terms(
+            self.num_vars,
+            self.order,
+            self.terms.iter().chain(&other.terms).cloned(),
+        ))
+    }
+
+    /// Subtracts one polynomial from another. The result uses the monomial order of `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if the numbers of variables differ.
+    pub fn sub(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.add(&other.neg())
+    }
+
+    /// Negates all coefficients of the polynomial.
+    pub fn neg(&self) -> Self {
+        Self {
+            num_vars: self.num_vars,
+            order: self.order,
+            terms: self.terms.iter().map(|(e, c)| (e.clone(), -c)).collect(),
+        }
+    }
+
+    /// Multiplies each coefficient by a scalar.
+    pub fn scalar_mul(&self, scalar: &BigInt) -> Self {
+        if scalar.is_zero() {
+            return Self::zero(self.num_vars, self.order);
+        }
+        Self {
+            num_vars: self.num_vars,
+            order: self.order,
+            terms: self
+                .terms
+                .iter()
+                .map(|(e, c)| (e.clone(), c * scalar))
+                .collect(),
+        }
+    }
+
+    /// Multiplies two polynomials term by term. The result uses the monomial order of `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if the numbers of variables differ.
+    pub fn mul(&self, other: &Self) -> Result<Self, PolynomialError> {
+        check_num_vars(self.num_vars, other.num_vars)?;
+        let products = self.terms.iter().flat_map(|(ea, ca)| {
+            other.terms.iter().map(move |(eb, cb)| {
+                let exponents = ea.iter().zip(eb).map(|(a, b)| a + b).collect();
+                (exponents, ca * cb)
+            })
+        });
+        Ok(Self::from_terms(self.num_vars, self.order, products))
+    }
+
+    /// Evaluates the polynomial at a point.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - One value per variable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if `point` does not have `num_vars` entries.
+    pub fn evaluate(&self, point: &[BigInt]) -> Result<BigInt, PolynomialError> {
+        check_num_vars(self.num_vars, point.len())?;
+        Ok(self
+            .terms
+            .iter()
+            .map(|(exponents, coeff)| {
+                exponents
+                    .iter()
+                    .zip(point)
+                    .filter(|(e, _)| **e > 0)
+                    .fold(coeff.clone(), |acc, (e, x)| acc * Pow::pow(x, *e))
+            })
+            .sum())
+    }
+
+    /// Substitutes a value for one variable.
+    ///
+    /// The number of variables is unchanged; `x_var` no longer occurs in the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` - The index of the variable to substitute.
+    /// * `value` - The value of `x_var`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if `var >= num_vars`.
+    pub fn partial_evaluate(&self, var: usize, value: &BigInt) -> Result<Self, PolynomialError> {
+        check_variable(var, self.num_vars)?;
+        let terms = self.terms.iter().map(|(exponents, coeff)| {
+            let mut exponents = exponents.clone();
+            let power = std::mem::take(&mut exponents[var]);
+            (exponents, coeff * Pow::pow(value, power))
+        });
+        Ok(Self::from_terms(self.num_vars, self.order, terms))
+    }
+}
+
+/// Checks that a variable index is in range.
+fn check_variable(var: usize, num_vars: usize) -> Result<(), PolynomialError> {
+    if var >= num_vars {
+        return Err(PolynomialError::DimensionError {
+            message: format!("Variable x{var} is out of range for {num_vars} variables"),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that two numbers of variables agree.
+fn check_num_vars(left: usize, right: usize) -> Result<(), PolynomialError> {
+    if left != right {
+        return Err(PolynomialError::DimensionError {
+            message: format!("Numbers of variables {left} and {right} do not match"),
+        });
+    }
+    Ok(())
+}
+
+impl fmt::Display for MultivariatePolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+
+        for (i, (exponents, coeff)) in self.terms.iter().enumerate() {
+            if i > 0 {
+                if coeff.sign() == num_bigint::Sign::Minus {
+                    write!(f, " - ")?;
+                } else {
+                    write!(f, " + ")?;
+                }
+            } else if coeff.sign() == num_bigint::Sign::Minus {
+                write!(f, "-")?;
+            }
+
+            let abs_coeff = coeff.magnitude();
+            let is_constant = exponents.iter().all(|e| *e == 0);
+            let mut first_factor = true;
+            if is_constant || !abs_coeff.is_one() {
+                write!(f, "{abs_coeff}")?;
+                first_factor = false;
+            }
+
+            for (var, &e) in exponents.iter().enumerate().filter(|(_, e)| **e > 0) {
+                if !first_factor {
+                    write!(f, "*")?;
+                }
+                first_factor = false;
+                write!(f, "x{var}")?;
+                if e > 1 {
+                    write!(f, "^{e}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
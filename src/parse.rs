@@ -0,0 +1,223 @@
+//! Parsing of polynomials from strings.
+//!
+//! The grammar accepts the output of `Display`, such as `3x^2 - x + 5`, together with common
+//! variants:
+//!
+//! - `*` between coefficient and variable and `**` for powers: `3*x**2`.
+//! - Arbitrary whitespace between tokens, and none at all: `3x^2-x+5`.
+//! - Terms in any order, with repeated exponents summed: `5 + x - x + 3x^2`.
+//! - Hexadecimal coefficients with a `0x` prefix: `0xff x^2`.
+//! - A unary sign after a binary operator: `x^2 + -3`.
+//!
+//! Exponents are limited to [`MAX_PARSED_DEGREE`], since the result is stored densely.
+//!
+//! Errors are reported as [`PolynomialError::SyntaxError`] with the byte offset of the problem.
+
+use crate::errors::PolynomialError;
+use crate::{Coefficient, GenericPolynomial, Polynomial};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use std::str::FromStr;
+
+/// The largest exponent accepted when parsing a polynomial.
+///
+/// A parsed polynomial stores one coefficient per degree, so an input such as `x^100000000000`
+/// would otherwise allocate without bound. Larger exponents are a `SyntaxError`.
+pub const MAX_PARSED_DEGREE: usize = 1 << 20;
+
+/// A cursor over the bytes of the input.
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consumes `token` if the input continues with it.
+    fn eat(&mut self, token: &str) -> bool {
+        if self.input[self.pos..].starts_with(token.as_bytes()) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error(&self, position: usize, message: impl Into<String>) -> PolynomialError {
+        PolynomialError::SyntaxError {
+            position,
+            message: message.into(),
+        }
+    }
+
+    /// Describes the next character for an error message.
+    fn unexpected(&self) -> PolynomialError {
+        let rest = std::str::from_utf8(&self.input[self.pos..]).unwrap_or_default();
+        match rest.chars().next() {
+            Some(c) => self.error(self.pos, format!("unexpected character '{c}'")),
+            None => self.error(self.pos, "unexpected end of input"),
+        }
+    }
+
+    /// Consumes a run of digits in `radix` and returns the start offset and the digits.
+    fn digits(&mut self, radix: u32) -> (usize, &'a [u8]) {
+        let start = self.pos;
+        while self.peek().is_some_and(|b| (b as char).is_digit(radix)) {
+            self.pos += 1;
+        }
+        (start, &self.input[start..self.pos])
+    }
+
+    /// Parses a decimal or `0x`-prefixed hexadecimal coefficient, if one starts here.
+    fn coefficient(&mut self) -> Result<Option<BigInt>, PolynomialError> {
+        if !self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            return Ok(None);
+        }
+        let start = self.pos;
+        // `0x` followed by a hex digit is a hex literal; otherwise it is `0 * x`.
+        let is_hex =
+            matches!(self.input[self.pos..], [b'0', b'x' | b'X', d, ..] if d.is_ascii_hexdigit());
+        let (radix, digits) = if is_hex {
+            self.pos += 2;
+            (16, self.digits(16).1)
+        } else {
+            (10, self.digits(10).1)
+        };
+        BigInt::parse_bytes(digits, radix)
+            .map(Some)
+            .ok_or_else(|| self.error(start, "invalid coefficient"))
+    }
+
+    /// Parses `x` with an optional `^n` or `**n` exponent, if a variable starts here.
+    fn variable(&mut self) -> Result<Option<usize>, PolynomialError> {
+        if !self.eat("x") {
+            return Ok(None);
+        }
+        let after_variable = self.pos;
+        self.skip_whitespace();
+        if !(self.eat("^") || self.eat("**")) {
+            self.pos = after_variable;
+            return Ok(Some(1));
+        }
+        self.skip_whitespace();
+        let (start, digits) = self.digits(10);
+        if digits.is_empty() {
+            return Err(self.error(start, "expected an exponent"));
+        }
+        std::str::from_utf8(digits)
+            .ok()
+            .and_then(|d| d.parse::<usize>().ok())
+            .filter(|&exponent| exponent <= MAX_PARSED_DEGREE)
+            .map(Some)
+            .ok_or_else(|| self.error(start, "exponent is too large"))
+    }
+
+    /// Parses a term without its sign: a coefficient, a variable, or both.
+    fn term(&mut self) -> Result<(usize, BigInt), PolynomialError> {
+        let coefficient = self.coefficient()?;
+        if let Some(c) = &coefficient {
+            let after_coefficient = self.pos;
+            self.skip_whitespace();
+            if self.eat("*") {
+                self.skip_whitespace();
+                return match self.variable()? {
+                    Some(exponent) => Ok((exponent, c.clone())),
+                    None => Err(self.error(self.pos, "expected 'x' after '*'")),
+                };
+            }
+            if self.peek() != Some(b'x') {
+                self.pos = after_coefficient;
+            }
+        }
+        match self.variable()? {
+            Some(exponent) => Ok((exponent, coefficient.unwrap_or_else(BigInt::one))),
+            None => match coefficient {
+                Some(c) => Ok((0, c)),
+                None => Err(self.unexpected()),
+            },
+        }
+    }
+
+    /// Parses zero or more `+`/`-` signs and returns whether the result is negative.
+    fn signs(&mut self) -> bool {
+        let mut negative = false;
+        loop {
+            self.skip_whitespace();
+            if self.eat("-") {
+                negative = !negative;
+            } else if !self.eat("+") {
+                return negative;
+            }
+        }
+    }
+
+    /// Parses the whole input into ascending coefficients.
+    fn polynomial(&mut self) -> Result<Vec<BigInt>, PolynomialError> {
+        let mut ascending: Vec<BigInt> = Vec::new();
+        let mut first = true;
+        loop {
+            self.skip_whitespace();
+            if self.peek().is_none() {
+                return if first {
+                    Err(self.error(self.pos, "expected a term"))
+                } else {
+                    Ok(ascending)
+                };
+            }
+            let operator = self.pos;
+            // A sign is optional before the first term and required between terms.
+            let negative = self.signs();
+            if !first && self.pos == operator {
+                return Err(self.unexpected());
+            }
+            first = false;
+
+            let (exponent, coefficient) = self.term()?;
+            if ascending.len() <= exponent {
+                ascending.resize(exponent + 1, BigInt::zero());
+            }
+            if negative {
+                ascending[exponent] -= coefficient;
+            } else {
+                ascending[exponent] += coefficient;
+            }
+        }
+    }
+}
+
+/// Parses the `Display` format of a polynomial in `x`, such as `3x^2 - x + 5`.
+///
+/// Also accepts `*` and `**` (`3*x**2`), any whitespace, terms in any order, `0x`-prefixed
+/// hexadecimal coefficients and a unary sign after an operator (`x + -3`). Coefficients of
+/// repeated exponents are summed and leading zero coefficients are removed.
+///
+/// # Errors
+///
+/// Returns `PolynomialError::SyntaxError` with the byte offset of the first problem, including
+/// an exponent above [`MAX_PARSED_DEGREE`], or
+/// `PolynomialError::ArithmeticError` if a coefficient does not fit in `C`.
+impl<C: Coefficient> FromStr for GenericPolynomial<C> {
+    type Err = PolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ascending = Parser::new(s).polynomial()?;
+        let poly = Polynomial::from_ascending_coefficients(ascending).trim_leading_zeros();
+        GenericPolynomial::try_from_polynomial(&poly)
+    }
+}
@@ -1,9 +1,13 @@
 //! Polynomial arithmetic implementation.
 
+use crate::coefficient::{Coefficient, SignedCoefficient};
 use crate::errors::PolynomialError;
-use crate::utils::reduce_and_center;
+use crate::utils::{
+    RoundingMode, check_ring_dimension, divide_and_round_scalar, reduce_and_center,
+    scale_and_round_scalar,
+};
 use num_bigint::BigInt;
-use num_traits::{One, Zero};
+use num_traits::{One, Signed, ToPrimitive, Zero};
 use std::fmt;
 
 #[cfg(feature = "serde")]
@@ -11,18 +15,63 @@ use serde::{Deserialize, Serialize};
 
 /// A polynomial represented by its coefficients in descending order of degree.
 ///
-/// The coefficients are stored as `BigInt` to support arbitrary precision arithmetic
-/// required for cryptographic operations. The polynomial is represented as:
+/// The coefficient type `C` is any [`Coefficient`]; see [`Polynomial`] for the arbitrary
+/// precision case used throughout the library. The polynomial is represented as:
 /// `a_n * x^n + a_{n-1} * x^{n-1} + ... + a_1 * x + a_0`
 ///
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Polynomial {
+pub struct GenericPolynomial<C> {
     /// Coefficients in descending order (highest degree first).
-    pub(crate) coefficients: Vec<BigInt>,
+    pub(crate) coefficients: Vec<C>,
+}
+
+/// A polynomial with `BigInt` coefficients.
+///
+/// The coefficients are stored as `BigInt` to support arbitrary precision arithmetic
+/// required for cryptographic operations.
+pub type Polynomial = GenericPolynomial<BigInt>;
+
+/// The result of reducing a polynomial in `Z_q[x]/(cyclo)` together with its quotients.
+///
+/// For an input polynomial `a`, the fields satisfy
+/// `result = a + r1 * q + r2 * cyclo` exactly over `Z[x]`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RingReduction {
+    /// The reduced polynomial, with centered coefficients.
+    pub result: Polynomial,
+    /// The quotient by the modulus `q`.
+    pub r1: Polynomial,
+    /// The quotient by the cyclotomic polynomial.
+    pub r2: Polynomial,
+}
+
+impl RingReduction {
+    /// Checks that `result = original + r1 * q + r2 * cyclo` holds exactly over `Z[x]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `original` - The polynomial that was reduced.
+    /// * `cyclo` - Coefficients of the cyclotomic polynomial.
+    /// * `modulus` - The modulus `q`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the identity holds, `false` otherwise.
+    pub fn verify(&self, original: &Polynomial, cyclo: &[BigInt], modulus: &BigInt) -> bool {
+        crate::utils::verify_ring_equation(
+            &self.result,
+            original,
+            &self.r1,
+            &self.r2,
+            cyclo,
+            modulus,
+        )
+    }
 }
 
-impl fmt::Display for Polynomial {
+impl<C: Coefficient> fmt::Display for GenericPolynomial<C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.coefficients.is_empty() {
             return write!(f, "0");
@@ -31,6 +80,7 @@ impl fmt::Display for Polynomial {
         let mut first = true;
         for (i, coeff) in self.coefficients.iter().enumerate() {
             let degree = self.coefficients.len() - 1 - i;
+            let coeff = &coeff.to_bigint();
 
             if coeff.is_zero() {
                 continue;
@@ -42,6 +92,8 @@ impl fmt::Display for Polynomial {
                 } else {
                     write!(f, " - ")?;
                 }
+            } else if coeff < &BigInt::zero() {
+                write!(f, "-")?;
             }
             first = false;
 
@@ -71,16 +123,67 @@ impl fmt::Display for Polynomial {
     }
 }
 
-impl Polynomial {
+impl<C> GenericPolynomial<C> {
     /// Creates a new polynomial from a vector of coefficients.
     ///
     /// # Arguments
     ///
     /// * `coefficients` - Vector of coefficients in descending order of degree.
-    pub fn new(coefficients: Vec<BigInt>) -> Self {
+    pub fn new(coefficients: Vec<C>) -> Self {
         Self { coefficients }
     }
 
+    /// Returns the coefficients of the polynomial.
+    pub fn coefficients(&self) -> &[C] {
+        &self.coefficients
+    }
+
+    /// Returns the degree of the polynomial.
+    ///
+    /// The degree of a zero polynomial is 0.
+    pub fn degree(&self) -> usize {
+        if self.coefficients.is_empty() {
+            0
+        } else {
+            self.coefficients.len() - 1
+        }
+    }
+
+    /// Applies `f` to every coefficient.
+    ///
+    /// This is the structural helper behind the coefficient-wise operations here and in the
+    /// residue polynomial types, which store their residues as a `GenericPolynomial`.
+    pub(crate) fn map<D>(&self, f: impl FnMut(&C) -> D) -> GenericPolynomial<D> {
+        GenericPolynomial {
+            coefficients: self.coefficients.iter().map(f).collect(),
+        }
+    }
+
+    /// Combines the coefficients of two polynomials term by term, aligning the constant terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other polynomial.
+    /// * `pad` - The value standing in for the missing high coefficients of the shorter one.
+    /// * `f` - The function combining a coefficient of `self` with one of `other`.
+    pub(crate) fn zip_with<'a, D>(
+        &'a self,
+        other: &'a Self,
+        pad: &'a C,
+        mut f: impl FnMut(&C, &C) -> D,
+    ) -> GenericPolynomial<D> {
+        let len = self.coefficients.len().max(other.coefficients.len());
+        let padded = |c: &'a [C]| std::iter::repeat_n(pad, len - c.len()).chain(c);
+        GenericPolynomial {
+            coefficients: padded(&self.coefficients)
+                .zip(padded(&other.coefficients))
+                .map(|(a, b)| f(a, b))
+                .collect(),
+        }
+    }
+}
+
+impl<C: Coefficient> GenericPolynomial<C> {
     /// Creates a polynomial from coefficients in ascending order format.
     ///
     /// This method converts from ascending order coefficient ordering (lowest degree first)
@@ -89,7 +192,7 @@ impl Polynomial {
     /// # Arguments
     ///
     /// * `ascending_coefficients` - Vector of coefficients in ascending order.
-    pub fn from_ascending_coefficients(ascending_coefficients: Vec<BigInt>) -> Self {
+    pub fn from_ascending_coefficients(ascending_coefficients: Vec<C>) -> Self {
         let mut coefficients = ascending_coefficients;
         coefficients.reverse();
         Self { coefficients }
@@ -103,7 +206,7 @@ impl Polynomial {
     /// # Returns
     ///
     /// Vector of coefficients in ascending order.
-    pub fn to_ascending_coefficients(&self) -> Vec<BigInt> {
+    pub fn to_ascending_coefficients(&self) -> Vec<C> {
         let mut coefficients = self.coefficients.clone();
         coefficients.reverse();
         coefficients
@@ -116,7 +219,7 @@ impl Polynomial {
     /// * `degree` - The degree of the zero polynomial.
     pub fn zero(degree: usize) -> Self {
         Self {
-            coefficients: vec![BigInt::zero(); degree + 1],
+            coefficients: vec![C::zero(); degree + 1],
         }
     }
 
@@ -125,28 +228,12 @@ impl Polynomial {
     /// # Arguments
     ///
     /// * `constant` - The constant value.
-    pub fn constant(constant: BigInt) -> Self {
+    pub fn constant(constant: C) -> Self {
         Self {
             coefficients: vec![constant],
         }
     }
 
-    /// Returns the coefficients of the polynomial.
-    pub fn coefficients(&self) -> &[BigInt] {
-        &self.coefficients
-    }
-
-    /// Returns the degree of the polynomial.
-    ///
-    /// The degree of a zero polynomial is 0.
-    pub fn degree(&self) -> usize {
-        if self.coefficients.is_empty() {
-            0
-        } else {
-            self.coefficients.len() - 1
-        }
-    }
-
     /// Checks if the polynomial is zero.
     pub fn is_zero(&self) -> bool {
         self.coefficients.iter().all(|c| c.is_zero())
@@ -161,7 +248,7 @@ impl Polynomial {
     }
 
     /// Returns the leading coefficient of the polynomial.
-    pub fn leading_coefficient(&self) -> Option<&BigInt> {
+    pub fn leading_coefficient(&self) -> Option<&C> {
         self.coefficients.first()
     }
 
@@ -180,70 +267,124 @@ impl Polynomial {
     ///
     /// A new polynomial containing the sum of the two polynomials.
     pub fn add(&self, other: &Self) -> Self {
-        let max_length = std::cmp::max(self.coefficients.len(), other.coefficients.len());
-        let mut result = vec![BigInt::zero(); max_length];
+        self.zip_with(other, &C::zero(), C::add_ref)
+    }
 
-        // Copy coefficients from the first polynomial
-        for (i, coeff) in self.coefficients.iter().enumerate() {
-            result[max_length - self.coefficients.len() + i] = coeff.clone();
+    /// Multiplies two polynomials using the naive algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the polynomial to multiply with `self`.
+    ///
+    /// # Returns
+    ///
+    /// A new polynomial containing the product.
+    pub fn mul(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero(0);
         }
 
-        // Add coefficients from the second polynomial
-        for (i, coeff) in other.coefficients.iter().enumerate() {
-            result[max_length - other.coefficients.len() + i] += coeff;
+        let product_len = self.coefficients.len() + other.coefficients.len() - 1;
+        let mut product = vec![C::zero(); product_len];
+
+        for i in 0..self.coefficients.len() {
+            for j in 0..other.coefficients.len() {
+                product[i + j]
+                    .add_assign_ref(&self.coefficients[i].mul_ref(&other.coefficients[j]));
+            }
         }
 
-        Polynomial::new(result)
+        Self::new(product)
     }
 
-    /// Subtracts one polynomial from another.
+    /// Multiplies each coefficient of the polynomial by a scalar.
     ///
     /// # Arguments
     ///
-    /// * `other` - A reference to the polynomial to subtract from `self`.
+    /// * `scalar` - A scalar to multiply with each coefficient.
     ///
     /// # Returns
     ///
-    /// A new polynomial containing the difference.
-    pub fn sub(&self, other: &Self) -> Self {
-        self.add(&other.neg())
+    /// A new polynomial with each coefficient multiplied by the scalar.
+    pub fn scalar_mul(&self, scalar: &C) -> Self {
+        self.map(|x| x.mul_ref(scalar))
     }
 
-    /// Negates all coefficients of the polynomial.
+    /// Evaluates the polynomial at a given point using Horner's method.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The point at which to evaluate the polynomial.
     ///
     /// # Returns
     ///
-    /// A new polynomial with all coefficients negated.
-    pub fn neg(&self) -> Self {
-        Polynomial::new(self.coefficients.iter().map(|x| -x).collect())
+    /// The value of the polynomial at the given point.
+    pub fn evaluate(&self, x: &C) -> C {
+        if self.coefficients.is_empty() {
+            return C::zero();
+        }
+
+        // Use Horner's method for efficient evaluation
+        let mut result = self.coefficients[0].clone();
+        for coeff in &self.coefficients[1..] {
+            result = result.mul_ref(x).add_ref(coeff);
+        }
+        result
     }
 
-    /// Multiplies two polynomials using the naive algorithm.
+    /// Converts the polynomial to one with `BigInt` coefficients.
+    pub fn to_bigint_polynomial(&self) -> Polynomial {
+        Polynomial::new(self.coefficients.iter().map(C::to_bigint).collect())
+    }
+
+    /// Converts a polynomial with `BigInt` coefficients to this coefficient type.
     ///
     /// # Arguments
     ///
-    /// * `other` - A reference to the polynomial to multiply with `self`.
+    /// * `polynomial` - The polynomial to convert.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A new polynomial containing the product.
-    pub fn mul(&self, other: &Self) -> Self {
-        if self.is_zero() || other.is_zero() {
-            return Polynomial::zero(0);
-        }
-
-        let product_len = self.coefficients.len() + other.coefficients.len() - 1;
-        let mut product = vec![BigInt::zero(); product_len];
+    /// Returns `PolynomialError::ArithmeticError` if a coefficient cannot be represented.
+    pub fn try_from_polynomial(polynomial: &Polynomial) -> Result<Self, PolynomialError> {
+        polynomial
+            .coefficients
+            .iter()
+            .map(|c| {
+                C::from_bigint(c).ok_or_else(|| PolynomialError::ArithmeticError {
+                    message: format!("Coefficient {c} does not fit in the coefficient type"),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+}
 
-        for i in 0..self.coefficients.len() {
-            for j in 0..other.coefficients.len() {
-                product[i + j] += &self.coefficients[i] * &other.coefficients[j];
-            }
-        }
+impl<C: SignedCoefficient> GenericPolynomial<C> {
+    /// Subtracts one polynomial from another.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the polynomial to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// A new polynomial containing the difference.
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
 
-        Polynomial::new(product)
+    /// Negates all coefficients of the polynomial.
+    ///
+    /// # Returns
+    ///
+    /// A new polynomial with all coefficients negated.
+    pub fn neg(&self) -> Self {
+        self.map(C::neg_ref)
     }
+}
 
+impl Polynomial {
     /// Divides one polynomial by another, returning the quotient and remainder.
     ///
     /// # Arguments
@@ -299,19 +440,6 @@ impl Polynomial {
         Ok((Polynomial::new(quotient), Polynomial::new(remainder)))
     }
 
-    /// Multiplies each coefficient of the polynomial by a scalar.
-    ///
-    /// # Arguments
-    ///
-    /// * `scalar` - A `BigInt` scalar to multiply with each coefficient.
-    ///
-    /// # Returns
-    ///
-    /// A new polynomial with each coefficient multiplied by the scalar.
-    pub fn scalar_mul(&self, scalar: &BigInt) -> Self {
-        Polynomial::new(self.coefficients.iter().map(|x| x * scalar).collect())
-    }
-
     /// Reduces the polynomial modulo a cyclotomic polynomial.
     ///
     /// This function performs polynomial division by the cyclotomic polynomial
@@ -341,6 +469,57 @@ impl Polynomial {
         Ok(Polynomial::new(out))
     }
 
+    /// Reduces the polynomial in the ring `Z_modulus[x]/(cyclo)`, keeping the quotients.
+    ///
+    /// Unlike [`crate::utils::reduce_in_ring`], which discards how the reduction was performed,
+    /// this returns the integer polynomials `r1` and `r2` such that
+    /// `result = self + r1 * modulus + r2 * cyclo` holds exactly over `Z[x]`. These quotients
+    /// are the witnesses needed to prove a ring equation in a circuit.
+    ///
+    /// # Arguments
+    ///
+    /// * `cyclo` - Coefficients of the cyclotomic polynomial, which must be monic.
+    /// * `modulus` - The modulus used to reduce and center the coefficients.
+    ///
+    /// # Returns
+    ///
+    /// A [`RingReduction`] holding the reduced polynomial and both quotients.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::CyclotomicError` if `cyclo` is not monic.
+    /// Returns an error if the division by `cyclo` fails.
+    pub fn reduce_in_ring_with_quotients(
+        &self,
+        cyclo: &[BigInt],
+        modulus: &BigInt,
+    ) -> Result<RingReduction, PolynomialError> {
+        if !cyclo.first().is_some_and(|c| c.is_one()) {
+            return Err(PolynomialError::CyclotomicError {
+                message: "Cyclotomic polynomial must be monic".to_string(),
+            });
+        }
+
+        let (cyclo_quotient, _) = self.div(&Polynomial::new(cyclo.to_vec()))?;
+        let remainder = self.reduce_by_cyclotomic(cyclo)?;
+        let result = remainder.reduce_and_center(modulus);
+
+        let r1 = Polynomial::new(
+            result
+                .coefficients
+                .iter()
+                .zip(&remainder.coefficients)
+                .map(|(reduced, original)| (reduced - original) / modulus)
+                .collect(),
+        );
+
+        Ok(RingReduction {
+            result,
+            r1,
+            r2: cyclo_quotient.neg(),
+        })
+    }
+
     /// Reduces coefficients modulo a prime and centers them.
     ///
     /// # Arguments
@@ -360,25 +539,418 @@ impl Polynomial {
         Polynomial::new(reduced_coeffs)
     }
 
-    /// Evaluates the polynomial at a given point using Horner's method.
+    /// Scales every coefficient by `numerator / denominator` and rounds the exact result.
+    ///
+    /// This computes `round(t * x / q)` as in BFV decryption and tensoring, with rounding that
+    /// behaves consistently for negative coefficients.
     ///
     /// # Arguments
     ///
-    /// * `x` - The point at which to evaluate the polynomial.
+    /// * `numerator` - The numerator of the scaling factor (e.g. `t`).
+    /// * `denominator` - The denominator of the scaling factor (e.g. `q`).
+    /// * `mode` - The rounding mode.
     ///
     /// # Returns
     ///
-    /// The value of the polynomial at the given point.
-    pub fn evaluate(&self, x: &BigInt) -> BigInt {
-        if self.coefficients.is_empty() {
-            return BigInt::zero();
+    /// A new polynomial with scaled and rounded coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DivisionByZero` if `denominator` is zero.
+    pub fn scale_and_round(
+        &self,
+        numerator: &BigInt,
+        denominator: &BigInt,
+        mode: RoundingMode,
+    ) -> Result<Self, PolynomialError> {
+        if denominator.is_zero() {
+            return Err(PolynomialError::DivisionByZero);
         }
+        Ok(Polynomial::new(
+            self.coefficients
+                .iter()
+                .map(|c| scale_and_round_scalar(c, numerator, denominator, mode))
+                .collect(),
+        ))
+    }
 
-        // Use Horner's method for efficient evaluation
-        let mut result = self.coefficients[0].clone();
-        for coeff in &self.coefficients[1..] {
-            result = result * x + coeff;
+    /// Switches the polynomial from modulus `from` to modulus `to` by scaling and rounding.
+    ///
+    /// Each coefficient `c` is centered modulo `from` and mapped to `round(c * to / from)`
+    /// (halves rounded away from zero), then centered modulo `to`. This is the modulus switch
+    /// used by BFV and CKKS.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The current modulus `Q`.
+    /// * `to` - The target modulus `Q'`.
+    ///
+    /// # Returns
+    ///
+    /// A new polynomial with coefficients centered modulo `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if either modulus is not positive.
+    pub fn switch_modulus(&self, from: &BigInt, to: &BigInt) -> Result<Self, PolynomialError> {
+        check_positive_modulus(from)?;
+        check_positive_modulus(to)?;
+
+        let coefficients = self
+            .reduce_and_center(from)
+            .coefficients
+            .iter()
+            .map(|c| scale_and_round_scalar(c, to, from, RoundingMode::NearestTiesAway))
+            .collect();
+        Ok(Polynomial::new(coefficients).reduce_and_center(to))
+    }
+
+    /// Switches the polynomial from modulus `from` to modulus `to`, preserving residues mod `t`.
+    ///
+    /// Each coefficient `c` is centered modulo `from` and mapped to the integer `c'` closest to
+    /// `c * to / from` such that `c' ≡ c (mod t)`, then centered modulo `to`. This is the BGV
+    /// modulus switch; it preserves decryption when `from ≡ to (mod t)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The current modulus `Q`.
+    /// * `to` - The target modulus `Q'`.
+    /// * `plaintext_modulus` - The plaintext modulus `t`.
+    ///
+    /// # Returns
+    ///
+    /// A new polynomial with coefficients centered modulo `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if a modulus is not positive.
+    pub fn bgv_switch_modulus(
+        &self,
+        from: &BigInt,
+        to: &BigInt,
+        plaintext_modulus: &BigInt,
+    ) -> Result<Self, PolynomialError> {
+        check_positive_modulus(from)?;
+        check_positive_modulus(to)?;
+        check_positive_modulus(plaintext_modulus)?;
+
+        // c' = c + t * k, with k = round((c * Q' - c * Q) / (Q * t)).
+        let denominator = from * plaintext_modulus;
+        let coefficients = self
+            .reduce_and_center(from)
+            .coefficients
+            .iter()
+            .map(|c| {
+                let k = divide_and_round_scalar(
+                    &(c * to - c * from),
+                    &denominator,
+                    RoundingMode::NearestTiesAway,
+                );
+                c + plaintext_modulus * k
+            })
+            .collect();
+        Ok(Polynomial::new(coefficients).reduce_and_center(to))
+    }
+
+    /// Divides the polynomial by a factor of its modulus and rounds, as in CKKS rescaling.
+    ///
+    /// Each coefficient `c` is centered modulo `modulus` and mapped to `round(c / divisor)`
+    /// (halves rounded away from zero), then centered modulo `modulus / divisor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - The current modulus `Q`.
+    /// * `divisor` - The prime `p` to divide by; must divide `Q`.
+    ///
+    /// # Returns
+    ///
+    /// A new polynomial with coefficients centered modulo `Q / p`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if a modulus is not positive or `p` does not
+    /// divide `Q`.
+    pub fn divide_and_round(
+        &self,
+        modulus: &BigInt,
+        divisor: &BigInt,
+    ) -> Result<Self, PolynomialError> {
+        check_positive_modulus(modulus)?;
+        check_positive_modulus(divisor)?;
+        if !(modulus % divisor).is_zero() {
+            return Err(PolynomialError::ModulusError {
+                message: format!("{divisor} does not divide the modulus {modulus}"),
+            });
         }
-        result
+
+        let coefficients = self
+            .reduce_and_center(modulus)
+            .coefficients
+            .iter()
+            .map(|c| divide_and_round_scalar(c, divisor, RoundingMode::NearestTiesAway))
+            .collect();
+        Ok(Polynomial::new(coefficients).reduce_and_center(&(modulus / divisor)))
+    }
+
+    /// Returns the coefficients to measure, centered modulo `modulus` if one is given.
+    fn norm_coefficients(&self, modulus: Option<&BigInt>) -> Vec<BigInt> {
+        match modulus {
+            Some(modulus) => self.reduce_and_center(modulus).coefficients,
+            None => self.coefficients.clone(),
+        }
+    }
+
+    /// Computes the infinity norm `max |a_i|` of the polynomial.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - If given, the norm of the centered representatives modulo `modulus`.
+    ///
+    /// # Returns
+    ///
+    /// The largest absolute value of a coefficient, or zero for an empty polynomial.
+    pub fn infinity_norm(&self, modulus: Option<&BigInt>) -> BigInt {
+        self.norm_coefficients(modulus)
+            .iter()
+            .map(|c| c.abs())
+            .max()
+            .unwrap_or_else(BigInt::zero)
+    }
+
+    /// Computes the L1 norm `sum |a_i|` of the polynomial.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - If given, the norm of the centered representatives modulo `modulus`.
+    pub fn l1_norm(&self, modulus: Option<&BigInt>) -> BigInt {
+        self.norm_coefficients(modulus)
+            .iter()
+            .map(|c| c.abs())
+            .sum()
+    }
+
+    /// Computes the squared L2 norm `sum a_i^2` of the polynomial.
+    ///
+    /// The square is returned so that the result stays an exact integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `modulus` - If given, the norm of the centered representatives modulo `modulus`.
+    pub fn l2_norm_squared(&self, modulus: Option<&BigInt>) -> BigInt {
+        self.norm_coefficients(modulus).iter().map(|c| c * c).sum()
+    }
+
+    /// Computes the canonical-embedding infinity norm in `Z[x]/(x^n + 1)`.
+    ///
+    /// This is `max |p(ζ)|` over the primitive `2n`-th roots of unity `ζ = exp(iπ(2j+1)/n)`,
+    /// computed in double precision. The polynomial is first reduced modulo `x^n + 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The ring dimension; must be a power of two.
+    /// * `modulus` - If given, the norm of the centered representatives modulo `modulus`.
+    ///
+    /// # Returns
+    ///
+    /// An approximation of the canonical-embedding norm.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::CyclotomicError` if `n` is not a power of two.
+    pub fn canonical_embedding_norm(
+        &self,
+        n: usize,
+        modulus: Option<&BigInt>,
+    ) -> Result<f64, PolynomialError> {
+        check_ring_dimension(n)?;
+
+        let mut cyclo = vec![BigInt::zero(); n + 1];
+        cyclo[0] = BigInt::one();
+        cyclo[n] = BigInt::one();
+        let reduced =
+            Polynomial::new(self.norm_coefficients(modulus)).reduce_by_cyclotomic(&cyclo)?;
+
+        // Ascending coefficients as floating point values.
+        let coeffs: Vec<f64> = reduced
+            .coefficients
+            .iter()
+            .rev()
+            .map(|c| c.to_f64().unwrap_or(f64::INFINITY))
+            .collect();
+
+        let norm = (0..n)
+            .map(|j| {
+                let theta = std::f64::consts::PI * (2 * j + 1) as f64 / n as f64;
+                let (re, im) = coeffs
+                    .iter()
+                    .enumerate()
+                    .fold((0.0, 0.0), |(re, im), (k, c)| {
+                        let angle = theta * k as f64;
+                        (re + c * angle.cos(), im + c * angle.sin())
+                    });
+                re.hypot(im)
+            })
+            .fold(0.0, f64::max);
+
+        Ok(norm)
+    }
+
+    /// Returns the `n` ascending coefficients of the polynomial reduced modulo `x^n + 1`.
+    ///
+    /// Coefficient `i` is folded onto `i mod n`, negated when `i / n` is odd.
+    pub(crate) fn negacyclic_ascending(&self, n: usize) -> Result<Vec<BigInt>, PolynomialError> {
+        check_ring_dimension(n)?;
+
+        let mut out = vec![BigInt::zero(); n];
+        for (i, coeff) in self.coefficients.iter().rev().enumerate() {
+            if (i / n) % 2 == 0 {
+                out[i % n] += coeff;
+            } else {
+                out[i % n] -= coeff;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Applies the Galois automorphism `σ_k: x ↦ x^k` in `Z[x]/(x^n + 1)`.
+    ///
+    /// The polynomial is first reduced modulo `x^n + 1`. Since `x^n = -1`, the automorphism is a
+    /// signed permutation of the coefficients: `a_i x^i` maps to `±a_i x^{ik mod n}`.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The Galois element; must be odd. It is taken modulo `2n`.
+    /// * `n` - The ring dimension; must be a power of two.
+    ///
+    /// # Returns
+    ///
+    /// A new polynomial with `n` coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::CyclotomicError` if `n` is not a power of two or `k` is even.
+    pub fn automorphism(&self, k: usize, n: usize) -> Result<Self, PolynomialError> {
+        let coeffs = self.negacyclic_ascending(n)?;
+        if k % 2 == 0 {
+            return Err(PolynomialError::CyclotomicError {
+                message: format!("Galois element {k} must be odd"),
+            });
+        }
+
+        let two_n = 2 * n;
+        let k = k % two_n;
+        let mut out = vec![BigInt::zero(); n];
+        for (i, coeff) in coeffs.into_iter().enumerate() {
+            let exponent = (i * k) % two_n;
+            if exponent < n {
+                out[exponent] = coeff;
+            } else {
+                out[exponent - n] = -coeff;
+            }
+        }
+
+        Ok(Polynomial::from_ascending_coefficients(out))
+    }
+
+    /// Computes the trace `Tr_{K/K'}` from `Z[x]/(x^n + 1)` down to its subring of dimension
+    /// `n / 2^k`.
+    ///
+    /// The subring is `Z[x^{2^k}]/(x^n + 1)`, fixed by the automorphisms `σ_j` with
+    /// `j ≡ 1 (mod 2n / 2^k)`. The trace is the sum of these `2^k` automorphisms, computed with
+    /// `k` automorphisms as `∏ (1 + σ_{n/2^{i-1} + 1})` for `i = 1..=k`. The result keeps the
+    /// coefficients of `x^{2^k m}` scaled by `2^k` and zeroes all others.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The ring dimension; must be a power of two.
+    /// * `k` - The number of halvings; must satisfy `2^k <= n`.
+    ///
+    /// # Returns
+    ///
+    /// A new polynomial with `n` coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::CyclotomicError` if `n` is not a power of two or `2^k > n`.
+    pub fn trace(&self, n: usize, k: u32) -> Result<Self, PolynomialError> {
+        check_ring_dimension(n)?;
+        if k > n.trailing_zeros() {
+            return Err(PolynomialError::CyclotomicError {
+                message: format!("Cannot take the trace of degree 2^{k} in dimension {n}"),
+            });
+        }
+
+        let mut result = Polynomial::from_ascending_coefficients(self.negacyclic_ascending(n)?);
+        for i in 1..=k {
+            let galois_element = (n >> (i - 1)) + 1;
+            result = result.add(&result.automorphism(galois_element, n)?);
+        }
+        Ok(result)
+    }
+
+    /// Computes the full trace `Tr_{K/Q}` of the polynomial in `Z[x]/(x^n + 1)`.
+    ///
+    /// This is the sum of all `n` Galois conjugates, which equals `n * a_0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The ring dimension; must be a power of two.
+    ///
+    /// # Returns
+    ///
+    /// A new constant polynomial with `n` coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::CyclotomicError` if `n` is not a power of two.
+    pub fn full_trace(&self, n: usize) -> Result<Self, PolynomialError> {
+        check_ring_dimension(n)?;
+        self.trace(n, n.trailing_zeros())
+    }
+
+    /// Multiplies the polynomial by the monomial `x^k` in `Z[x]/(x^n + 1)`.
+    ///
+    /// This is a negacyclic rotation of the coefficients computed in `O(n)`, rather than the
+    /// `O(n^2)` of [`Self::mul`] followed by [`Self::reduce_by_cyclotomic`]. Since
+    /// `x^{2n} = 1` in the ring, `k` may be negative or at least `2n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The exponent of the monomial.
+    /// * `n` - The ring dimension; must be a power of two.
+    ///
+    /// # Returns
+    ///
+    /// A new polynomial with `n` coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::CyclotomicError` if `n` is not a power of two.
+    pub fn mul_by_monomial(&self, k: i64, n: usize) -> Result<Self, PolynomialError> {
+        let coeffs = self.negacyclic_ascending(n)?;
+        let two_n = 2 * n;
+        let shift = k.rem_euclid(two_n as i64) as usize;
+
+        let mut out = vec![BigInt::zero(); n];
+        for (i, coeff) in coeffs.into_iter().enumerate() {
+            let exponent = (i + shift) % two_n;
+            if exponent < n {
+                out[exponent] = coeff;
+            } else {
+                out[exponent - n] = -coeff;
+            }
+        }
+
+        Ok(Polynomial::from_ascending_coefficients(out))
+    }
+}
+
+/// Checks that a modulus is positive.
+fn check_positive_modulus(modulus: &BigInt) -> Result<(), PolynomialError> {
+    if modulus <= &BigInt::zero() {
+        return Err(PolynomialError::ModulusError {
+            message: format!("Modulus {modulus} must be positive"),
+        });
     }
+    Ok(())
 }
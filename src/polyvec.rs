@@ -0,0 +1,433 @@
+//! Vectors and matrices of polynomials for module lattices.
+//!
+//! Module-LWE schemes and the proofs built on them work over `R_q^k`, where
+//! `R_q = Z_q[x]/(cyclo)`. [`PolyVec`] is a vector of ring elements and [`PolyMatrix`] a
+//! row-major matrix of them. Products are computed over `Z[x]` and then reduced in the ring,
+//! i.e. by the cyclotomic polynomial and then centered modulo `q`, as in
+//! [`crate::utils::reduce_in_ring`]. Coefficient-wise reductions mirror
+//! [`crate::utils::reduce_coefficients_2d`].
+
+use crate::Polynomial;
+use crate::errors::PolynomialError;
+use crate::utils::{reduce_coefficients, reduce_in_ring};
+use num_bigint::BigInt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Returns a map reducing a polynomial in `Z_modulus[x]/(cyclo)` with centered coefficients.
+///
+/// The reduction by `cyclo` fails only when `cyclo` itself is invalid, in which case
+/// [`crate::utils::reduce_in_ring`] panics, so `cyclo` is checked once up front.
+fn ring_reduction<'a>(
+    cyclo: &'a [BigInt],
+    modulus: &'a BigInt,
+) -> Result<impl Fn(&Polynomial) -> Polynomial + 'a, PolynomialError> {
+    Polynomial::zero(0).reduce_by_cyclotomic(cyclo)?;
+    Ok(move |poly: &Polynomial| {
+        let mut coefficients = poly.coefficients().to_vec();
+        reduce_in_ring(&mut coefficients, cyclo, modulus);
+        Polynomial::new(coefficients)
+    })
+}
+
+/// Computes `sum(a_i * b_i)` over `Z[x]` for slices of equal length.
+fn dot(a: &[Polynomial], b: &[Polynomial]) -> Result<Polynomial, PolynomialError> {
+    check_dimension("Vector lengths", a.len(), b.len())?;
+    Ok(a.iter()
+        .zip(b)
+        .fold(Polynomial::zero(0), |acc, (a, b)| acc.add(&a.mul(b))))
+}
+
+/// Checks that two dimensions agree.
+fn check_dimension(what: &str, left: usize, right: usize) -> Result<(), PolynomialError> {
+    if left != right {
+        return Err(PolynomialError::DimensionError {
+            message: format!("{what} {left} and {right} do not match"),
+        });
+    }
+    Ok(())
+}
+
+/// A vector of polynomials.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PolyVec {
+    polys: Vec<Polynomial>,
+}
+
+impl PolyVec {
+    /// Creates a vector from its entries.
+    pub fn new(polys: Vec<Polynomial>) -> Self {
+        Self { polys }
+    }
+
+    /// Creates a vector of `len` zero polynomials of the given degree.
+    pub fn zero(len: usize, degree: usize) -> Self {
+        Self::new(vec![Polynomial::zero(degree); len])
+    }
+
+    /// Creates a vector from the coefficients of each entry, in descending order.
+    pub fn from_coefficients_2d(coefficient_matrix: Vec<Vec<BigInt>>) -> Self {
+        Self::new(
+            coefficient_matrix
+                .into_iter()
+                .map(Polynomial::new)
+                .collect(),
+        )
+    }
+
+    /// Returns the coefficients of each entry, in descending order.
+    pub fn to_coefficients_2d(&self) -> Vec<Vec<BigInt>> {
+        self.polys
+            .iter()
+            .map(|p| p.coefficients().to_vec())
+            .collect()
+    }
+
+    /// Returns the entries.
+    pub fn polynomials(&self) -> &[Polynomial] {
+        &self.polys
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.polys.len()
+    }
+
+    /// Returns `true` if the vector has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.polys.is_empty()
+    }
+
+    /// Combines two vectors of the same length entry by entry.
+    fn zip_with(
+        &self,
+        other: &Self,
+        f: impl Fn(&Polynomial, &Polynomial) -> Polynomial,
+    ) -> Result<Self, PolynomialError> {
+        check_dimension("Vector lengths", self.len(), other.len())?;
+        Ok(Self::new(
+            self.polys
+                .iter()
+                .zip(&other.polys)
+                .map(|(a, b)| f(a, b))
+                .collect(),
+        ))
+    }
+
+    /// Adds two vectors entry by entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if the lengths differ.
+    pub fn add(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.zip_with(other, Polynomial::add)
+    }
+
+    /// Subtracts `other` from `self` entry by entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if the lengths differ.
+    pub fn sub(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.zip_with(other, Polynomial::sub)
+    }
+
+    /// Applies a polynomial map to every entry.
+    fn map(&self, f: impl Fn(&Polynomial) -> Polynomial) -> Self {
+        Self::new(self.polys.iter().map(f).collect())
+    }
+
+    /// Negates every entry.
+    pub fn neg(&self) -> Self {
+        self.map(Polynomial::neg)
+    }
+
+    /// Multiplies every entry by a ring element, over `Z[x]`.
+    pub fn scalar_mul(&self, scalar: &Polynomial) -> Self {
+        self.map(|p| p.mul(scalar))
+    }
+
+    /// Computes `sum(a_i * b_i)` over `Z[x]`, without reduction.
+    ///
+    /// This is the unreduced value whose ring reduction quotients are needed as witnesses.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if the lengths differ.
+    pub fn dot(&self, other: &Self) -> Result<Polynomial, PolynomialError> {
+        dot(&self.polys, &other.polys)
+    }
+
+    /// Computes the inner product `sum(a_i * b_i)` in `Z_modulus[x]/(cyclo)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other vector.
+    /// * `cyclo` - Coefficients of the cyclotomic polynomial.
+    /// * `modulus` - The modulus used to reduce and center the coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if the lengths differ, and the errors of
+    /// [`Polynomial::reduce_by_cyclotomic`].
+    pub fn inner_product(
+        &self,
+        other: &Self,
+        cyclo: &[BigInt],
+        modulus: &BigInt,
+    ) -> Result<Polynomial, PolynomialError> {
+        let dot = self.dot(other)?;
+        Ok(ring_reduction(cyclo, modulus)?(&dot))
+    }
+
+    /// Reduces every coefficient to `[0, p)`, as [`crate::utils::reduce_coefficients_2d`].
+    pub fn reduce_coefficients(&self, p: &BigInt) -> Self {
+        self.map(|poly| Polynomial::new(reduce_coefficients(poly.coefficients(), p)))
+    }
+
+    /// Reduces and centers every coefficient modulo `modulus`.
+    pub fn reduce_and_center(&self, modulus: &BigInt) -> Self {
+        self.map(|p| p.reduce_and_center(modulus))
+    }
+
+    /// Reduces every entry in `Z_modulus[x]/(cyclo)`, as [`crate::utils::reduce_in_ring`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Polynomial::reduce_by_cyclotomic`].
+    pub fn reduce_in_ring(
+        &self,
+        cyclo: &[BigInt],
+        modulus: &BigInt,
+    ) -> Result<Self, PolynomialError> {
+        Ok(self.map(ring_reduction(cyclo, modulus)?))
+    }
+}
+
+/// A row-major matrix of polynomials.
+///
+/// Deserialization checks that the number of entries is `rows * cols`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "RawPolyMatrix", into = "RawPolyMatrix")
+)]
+pub struct PolyMatrix {
+    rows: usize,
+    cols: usize,
+    /// Entries in row-major order.
+    entries: PolyVec,
+}
+
+impl PolyMatrix {
+    /// Creates a matrix from its rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if the rows have different lengths.
+    pub fn new(rows: Vec<Vec<Polynomial>>) -> Result<Self, PolynomialError> {
+        let cols = rows.first().map_or(0, Vec::len);
+        for row in &rows {
+            check_dimension("Row lengths", cols, row.len())?;
+        }
+        Ok(Self {
+            rows: rows.len(),
+            cols,
+            entries: PolyVec::new(rows.into_iter().flatten().collect()),
+        })
+    }
+
+    /// Creates a `rows x cols` matrix of zero polynomials of the given degree.
+    pub fn zero(rows: usize, cols: usize, degree: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            entries: PolyVec::zero(rows * cols, degree),
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the entry in row `i` and column `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> &Polynomial {
+        assert!(
+            i < self.rows && j < self.cols,
+            "index ({i}, {j}) out of bounds"
+        );
+        &self.entries.polys[i * self.cols + j]
+    }
+
+    /// Returns row `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn row(&self, i: usize) -> &[Polynomial] {
+        &self.entries.polys[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns a matrix of the same shape with the given entries.
+    fn with_entries(&self, entries: PolyVec) -> Self {
+        Self {
+            rows: self.rows,
+            cols: self.cols,
+            entries,
+        }
+    }
+
+    /// Returns the transpose of the matrix.
+    pub fn transpose(&self) -> Self {
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            entries: PolyVec::new(
+                (0..self.cols)
+                    .flat_map(|j| (0..self.rows).map(move |i| (i, j)))
+                    .map(|(i, j)| self.get(i, j).clone())
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Adds two matrices entry by entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if the shapes differ.
+    pub fn add(&self, other: &Self) -> Result<Self, PolynomialError> {
+        check_dimension("Row counts", self.rows, other.rows)?;
+        check_dimension("Column counts", self.cols, other.cols)?;
+        Ok(self.with_entries(self.entries.add(&other.entries)?))
+    }
+
+    /// Computes the matrix-vector product `A * v` in `Z_modulus[x]/(cyclo)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A vector with one entry per column.
+    /// * `cyclo` - Coefficients of the cyclotomic polynomial.
+    /// * `modulus` - The modulus used to reduce and center the coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if the vector length differs from the number
+    /// of columns, and the errors of [`Polynomial::reduce_by_cyclotomic`].
+    pub fn mul_vec(
+        &self,
+        v: &PolyVec,
+        cyclo: &[BigInt],
+        modulus: &BigInt,
+    ) -> Result<PolyVec, PolynomialError> {
+        check_dimension("Column count and vector length", self.cols, v.len())?;
+        let reduce = ring_reduction(cyclo, modulus)?;
+        (0..self.rows)
+            .map(|i| dot(self.row(i), v.polynomials()).map(|p| reduce(&p)))
+            .collect::<Result<Vec<_>, _>>()
+            .map(PolyVec::new)
+    }
+
+    /// Computes the matrix product `A * B` in `Z_modulus[x]/(cyclo)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DimensionError` if the number of columns of `self` differs from
+    /// the number of rows of `other`, and the errors of [`Polynomial::reduce_by_cyclotomic`].
+    pub fn mul(
+        &self,
+        other: &Self,
+        cyclo: &[BigInt],
+        modulus: &BigInt,
+    ) -> Result<Self, PolynomialError> {
+        check_dimension("Column count and row count", self.cols, other.rows)?;
+        let reduce = ring_reduction(cyclo, modulus)?;
+        let columns = other.transpose();
+        let mut entries = Vec::with_capacity(self.rows * other.cols);
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                entries.push(reduce(&dot(self.row(i), columns.row(j))?));
+            }
+        }
+        Ok(Self {
+            rows: self.rows,
+            cols: other.cols,
+            entries: PolyVec::new(entries),
+        })
+    }
+
+    /// Reduces every coefficient to `[0, p)`, as [`crate::utils::reduce_coefficients_2d`].
+    pub fn reduce_coefficients(&self, p: &BigInt) -> Self {
+        self.with_entries(self.entries.reduce_coefficients(p))
+    }
+
+    /// Reduces and centers every coefficient modulo `modulus`.
+    pub fn reduce_and_center(&self, modulus: &BigInt) -> Self {
+        self.with_entries(self.entries.reduce_and_center(modulus))
+    }
+
+    /// Reduces every entry in `Z_modulus[x]/(cyclo)`, as [`crate::utils::reduce_in_ring`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Polynomial::reduce_by_cyclotomic`].
+    pub fn reduce_in_ring(
+        &self,
+        cyclo: &[BigInt],
+        modulus: &BigInt,
+    ) -> Result<Self, PolynomialError> {
+        Ok(self.with_entries(self.entries.reduce_in_ring(cyclo, modulus)?))
+    }
+}
+
+/// The serialized form of a [`PolyMatrix`], with its entries in row-major order.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct RawPolyMatrix {
+    rows: usize,
+    cols: usize,
+    entries: Vec<Polynomial>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawPolyMatrix> for PolyMatrix {
+    type Error = PolynomialError;
+
+    fn try_from(raw: RawPolyMatrix) -> Result<Self, Self::Error> {
+        // `Vec` lengths never reach `usize::MAX`, so a saturated product is always rejected.
+        check_dimension(
+            "Shape size and entry count",
+            raw.rows.saturating_mul(raw.cols),
+            raw.entries.len(),
+        )?;
+        Ok(Self {
+            rows: raw.rows,
+            cols: raw.cols,
+            entries: PolyVec::new(raw.entries),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<PolyMatrix> for RawPolyMatrix {
+    fn from(matrix: PolyMatrix) -> Self {
+        Self {
+            rows: matrix.rows,
+            cols: matrix.cols,
+            entries: matrix.entries.polys,
+        }
+    }
+}
@@ -0,0 +1,353 @@
+//! Polynomials with rational coefficients.
+//!
+//! Lagrange interpolation through integer points and exact division of integer polynomials
+//! generally produce fractional coefficients, which [`Polynomial`] cannot hold.
+//! [`RationalPolynomial`] stores its coefficients as `BigRational` in descending order, like
+//! [`Polynomial`], and converts back either exactly, failing on a fractional coefficient, or by
+//! clearing denominators.
+
+use crate::Polynomial;
+use crate::errors::PolynomialError;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A polynomial with `BigRational` coefficients in descending order of degree.
+///
+/// Leading zero coefficients are removed, so the zero polynomial has no coefficients and two
+/// equal polynomials have equal representations. Deserialization removes them as well.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(from = "RawRationalPolynomial")
+)]
+pub struct RationalPolynomial {
+    coefficients: Vec<BigRational>,
+}
+
+/// The serialized form of a [`RationalPolynomial`], which may have leading zeros.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawRationalPolynomial {
+    coefficients: Vec<BigRational>,
+}
+
+#[cfg(feature = "serde")]
+impl From<RawRationalPolynomial> for RationalPolynomial {
+    fn from(raw: RawRationalPolynomial) -> Self {
+        Self::new(raw.coefficients)
+    }
+}
+
+impl RationalPolynomial {
+    /// Creates a new polynomial, removing leading zero coefficients.
+    ///
+    /// # Arguments
+    ///
+    /// * `coefficients` - Vector of coefficients in descending order of degree.
+    pub fn new(coefficients: Vec<BigRational>) -> Self {
+        let leading = coefficients
+            .iter()
+            .position(|c| !c.is_zero())
+            .unwrap_or(coefficients.len());
+        Self {
+            coefficients: coefficients[leading..].to_vec(),
+        }
+    }
+
+    /// Creates a polynomial from coefficients in ascending order of degree.
+    pub fn from_ascending_coefficients(mut ascending_coefficients: Vec<BigRational>) -> Self {
+        ascending_coefficients.reverse();
+        Self::new(ascending_coefficients)
+    }
+
+    /// Returns the coefficients in ascending order of degree.
+    pub fn to_ascending_coefficients(&self) -> Vec<BigRational> {
+        self.coefficients.iter().rev().cloned().collect()
+    }
+
+    /// Creates the zero polynomial.
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Creates a constant polynomial.
+    pub fn constant(constant: BigRational) -> Self {
+        Self::new(vec![constant])
+    }
+
+    /// Converts an integer polynomial.
+    pub fn from_polynomial(poly: &Polynomial) -> Self {
+        Self::new(
+            poly.coefficients()
+                .iter()
+                .map(|c| BigRational::from_integer(c.clone()))
+                .collect(),
+        )
+    }
+
+    /// Converts to an integer polynomial if every coefficient is an integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ArithmeticError` if a coefficient is not an integer.
+    pub fn to_polynomial(&self) -> Result<Polynomial, PolynomialError> {
+        if let Some(c) = self.coefficients.iter().find(|c| !c.is_integer()) {
+            return Err(PolynomialError::ArithmeticError {
+                message: format!("Coefficient {c} is not an integer"),
+            });
+        }
+        Ok(self.integer_polynomial())
+    }
+
+    /// Scales the polynomial to an integer polynomial by the least common multiple of the
+    /// denominators.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(poly, d)` with `d > 0` the smallest positive integer such that `d * self` has
+    /// integer coefficients, and `poly = d * self`.
+    pub fn clear_denominators(&self) -> (Polynomial, BigInt) {
+        let denominator = self
+            .coefficients
+            .iter()
+            .fold(BigInt::one(), |acc, c| acc.lcm(c.denom()));
+        let scaled = self.scalar_mul(&BigRational::from_integer(denominator.clone()));
+        (scaled.integer_polynomial(), denominator)
+    }
+
+    /// Converts a polynomial whose coefficients are known to be integers.
+    fn integer_polynomial(&self) -> Polynomial {
+        if self.coefficients.is_empty() {
+            return Polynomial::zero(0);
+        }
+        Polynomial::new(self.coefficients.iter().map(|c| c.to_integer()).collect())
+    }
+
+    /// Returns the coefficients in descending order of degree.
+    pub fn coefficients(&self) -> &[BigRational] {
+        &self.coefficients
+    }
+
+    /// Returns the degree of the polynomial.
+    ///
+    /// The degree of a zero polynomial is 0.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    /// Checks if the polynomial is zero.
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Returns the leading coefficient, or `None` for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Option<&BigRational> {
+        self.coefficients.first()
+    }
+
+    /// Adds two polynomials.
+    pub fn add(&self, other: &Self) -> Self {
+        let (long, short) = if self.coefficients.len() >= other.coefficients.len() {
+            (&self.coefficients, &other.coefficients)
+        } else {
+            (&other.coefficients, &self.coefficients)
+        };
+        let offset = long.len() - short.len();
+        let mut result = long.clone();
+        for (r, s) in result[offset..].iter_mut().zip(short) {
+            *r += s;
+        }
+        Self::new(result)
+    }
+
+    /// Subtracts one polynomial from another.
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    /// Negates all coefficients of the polynomial.
+    pub fn neg(&self) -> Self {
+        Self {
+            coefficients: self.coefficients.iter().map(|c| -c).collect(),
+        }
+    }
+
+    /// Multiplies two polynomials.
+    pub fn mul(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        let mut result =
+            vec![BigRational::zero(); self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in other.coefficients.iter().enumerate() {
+                result[i + j] += a * b;
+            }
+        }
+        Self::new(result)
+    }
+
+    /// Multiplies each coefficient by a scalar.
+    pub fn scalar_mul(&self, scalar: &BigRational) -> Self {
+        if scalar.is_zero() {
+            return Self::zero();
+        }
+        Self {
+            coefficients: self.coefficients.iter().map(|c| c * scalar).collect(),
+        }
+    }
+
+    /// Evaluates the polynomial at a point using Horner's method.
+    pub fn evaluate(&self, x: &BigRational) -> BigRational {
+        self.coefficients
+            .iter()
+            .fold(BigRational::zero(), |acc, c| acc * x + c)
+    }
+
+    /// Divides by another polynomial with remainder.
+    ///
+    /// # Arguments
+    ///
+    /// * `divisor` - The divisor polynomial.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(quotient, remainder)` with `self = quotient * divisor + remainder` and
+    /// `remainder` of lower degree than `divisor`, or zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DivisionByZero` if the divisor is zero.
+    pub fn div(&self, divisor: &Self) -> Result<(Self, Self), PolynomialError> {
+        let Some(leading) = divisor.leading_coefficient() else {
+            return Err(PolynomialError::DivisionByZero);
+        };
+        if self.coefficients.len() < divisor.coefficients.len() {
+            return Ok((Self::zero(), self.clone()));
+        }
+
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = Vec::with_capacity(remainder.len() - divisor.coefficients.len() + 1);
+        for i in 0..=remainder.len() - divisor.coefficients.len() {
+            let coeff = &remainder[i] / leading;
+            for (r, d) in remainder[i..].iter_mut().zip(&divisor.coefficients) {
+                *r -= d * &coeff;
+            }
+            quotient.push(coeff);
+        }
+
+        let split = quotient.len();
+        Ok((Self::new(quotient), Self::new(remainder.split_off(split))))
+    }
+
+    /// Divides by another polynomial that divides `self` exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::DivisionByZero` if the divisor is zero, or
+    /// `PolynomialError::ArithmeticError` if the remainder is not zero.
+    pub fn exact_div(&self, divisor: &Self) -> Result<Self, PolynomialError> {
+        let (quotient, remainder) = self.div(divisor)?;
+        if !remainder.is_zero() {
+            return Err(PolynomialError::ArithmeticError {
+                message: format!("Division leaves remainder {remainder}"),
+            });
+        }
+        Ok(quotient)
+    }
+
+    /// Computes the unique polynomial of degree less than `points.len()` through the given
+    /// points, using Newton's divided differences.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - `(x, y)` pairs with distinct `x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ArithmeticError` if two points share an `x` coordinate.
+    pub fn interpolate(points: &[(BigRational, BigRational)]) -> Result<Self, PolynomialError> {
+        let xs: Vec<&BigRational> = points.iter().map(|(x, _)| x).collect();
+        let mut differences: Vec<BigRational> = points.iter().map(|(_, y)| y.clone()).collect();
+
+        // After step `k`, `differences[i]` holds the divided difference f[x_{i-k}, ..., x_i].
+        for k in 1..points.len() {
+            for i in (k..points.len()).rev() {
+                let dx = xs[i] - xs[i - k];
+                if dx.is_zero() {
+                    return Err(PolynomialError::ArithmeticError {
+                        message: format!("Duplicate interpolation point x = {}", xs[i]),
+                    });
+                }
+                differences[i] = (&differences[i] - &differences[i - 1]) / dx;
+            }
+        }
+
+        // Newton form evaluated symbolically by Horner's method.
+        let mut result = Self::zero();
+        for (x, coeff) in xs.iter().zip(&differences).rev() {
+            let factor = Self::new(vec![BigRational::one(), -*x]);
+            result = result.mul(&factor).add(&Self::constant(coeff.clone()));
+        }
+        Ok(result)
+    }
+}
+
+impl From<&Polynomial> for RationalPolynomial {
+    fn from(poly: &Polynomial) -> Self {
+        Self::from_polynomial(poly)
+    }
+}
+
+impl fmt::Display for RationalPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.coefficients.is_empty() {
+            return write!(f, "0");
+        }
+
+        let mut first = true;
+        for (i, coeff) in self.coefficients.iter().enumerate() {
+            let degree = self.coefficients.len() - 1 - i;
+            if coeff.is_zero() {
+                continue;
+            }
+
+            if first {
+                if coeff.is_negative() {
+                    write!(f, "-")?;
+                }
+            } else if coeff.is_negative() {
+                write!(f, " - ")?;
+            } else {
+                write!(f, " + ")?;
+            }
+            first = false;
+
+            let abs_coeff = coeff.abs();
+            if degree == 0 {
+                write!(f, "{abs_coeff}")?;
+            } else if abs_coeff.is_integer() {
+                if !abs_coeff.is_one() {
+                    write!(f, "{abs_coeff}")?;
+                }
+            } else {
+                write!(f, "({abs_coeff})")?;
+            }
+
+            if degree > 0 {
+                write!(f, "x")?;
+                if degree > 1 {
+                    write!(f, "^{degree}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
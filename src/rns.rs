@@ -0,0 +1,404 @@
+//! Residue number system (RNS) representation of polynomials.
+//!
+//! A polynomial modulo `Q = q_0 * ... * q_{k-1}` is stored as one [`Polynomial`] per limb `q_i`,
+//! holding the coefficients reduced to `[0, q_i)`. This module provides the exact CRT conversion
+//! to and from [`Polynomial`], and the base-conversion primitives used by BFV multiplication:
+//!
+//! - [`RnsPolynomial::fast_base_conversion`]: the approximate conversion
+//!   `FastBConv(x) = sum([x_i * (Q/q_i)^{-1}]_{q_i} * Q/q_i)`, which equals `x + α * Q` for
+//!   some `0 <= α < k`.
+//! - [`RnsPolynomial::shenoy_kumaresan_conversion`]: the exact conversion of BEHZ, which removes
+//!   the `α * Q` overflow using a redundant modulus.
+//! - [`RnsPolynomial::scale_and_round_hps`]: the HPS computation of `round(t * x / Q) mod t`
+//!   from exact integer parts and per-limb fractional parts accumulated in double precision.
+
+use crate::Polynomial;
+use crate::errors::PolynomialError;
+use crate::utils::reduce_coefficients;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+
+/// A basis of pairwise coprime RNS moduli with its CRT constants.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RnsBasis {
+    /// The moduli `q_i`.
+    moduli: Vec<BigInt>,
+    /// The product `Q` of all moduli.
+    product: BigInt,
+    /// The punctured products `Q / q_i`.
+    punctured: Vec<BigInt>,
+    /// The inverses `(Q / q_i)^{-1} mod q_i`.
+    inverses: Vec<BigInt>,
+}
+
+impl RnsBasis {
+    /// Creates an RNS basis.
+    ///
+    /// # Arguments
+    ///
+    /// * `moduli` - The pairwise coprime moduli, each greater than 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if the basis is empty, a modulus is not greater
+    /// than 1, or two moduli share a factor.
+    pub fn new(moduli: Vec<BigInt>) -> Result<Self, PolynomialError> {
+        if moduli.is_empty() {
+            return Err(PolynomialError::ModulusError {
+                message: "RNS basis cannot be empty".to_string(),
+            });
+        }
+        if let Some(q) = moduli.iter().find(|q| *q <= &BigInt::one()) {
+            return Err(PolynomialError::ModulusError {
+                message: format!("RNS modulus {q} must be greater than 1"),
+            });
+        }
+        for (i, a) in moduli.iter().enumerate() {
+            for b in &moduli[i + 1..] {
+                if !a.gcd(b).is_one() {
+                    return Err(PolynomialError::ModulusError {
+                        message: format!("RNS moduli {a} and {b} are not coprime"),
+                    });
+                }
+            }
+        }
+
+        let product: BigInt = moduli.iter().product();
+        let punctured: Vec<BigInt> = moduli.iter().map(|q| &product / q).collect();
+        let inverses = punctured
+            .iter()
+            .zip(&moduli)
+            .map(|(p, q)| {
+                (p % q)
+                    .modinv(q)
+                    .expect("punctured product is invertible modulo a coprime limb")
+            })
+            .collect();
+
+        Ok(Self {
+            moduli,
+            product,
+            punctured,
+            inverses,
+        })
+    }
+
+    /// Returns the moduli `q_i`.
+    pub fn moduli(&self) -> &[BigInt] {
+        &self.moduli
+    }
+
+    /// Returns the product `Q` of all moduli.
+    pub fn product(&self) -> &BigInt {
+        &self.product
+    }
+
+    /// Returns the number of limbs.
+    pub fn len(&self) -> usize {
+        self.moduli.len()
+    }
+
+    /// Returns `true` if the basis has no limbs. A valid basis is never empty.
+    pub fn is_empty(&self) -> bool {
+        self.moduli.is_empty()
+    }
+
+    /// Computes `y_i = [x_i * (Q/q_i)^{-1}]_{q_i}` for one coefficient given its residues.
+    fn scaled_residues<'a>(&'a self, residues: impl Iterator<Item = &'a BigInt>) -> Vec<BigInt> {
+        residues
+            .zip(self.inverses.iter().zip(&self.moduli))
+            .map(|(x, (inv, q))| (x * inv).mod_floor(q))
+            .collect()
+    }
+}
+
+/// A polynomial in RNS form: one limb per modulus, with coefficients in `[0, q_i)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RnsPolynomial {
+    /// The residue polynomials, one per modulus of the basis.
+    limbs: Vec<Polynomial>,
+}
+
+impl RnsPolynomial {
+    /// Creates an RNS polynomial from residue polynomials.
+    ///
+    /// # Arguments
+    ///
+    /// * `limbs` - The residue polynomials, one per modulus of `basis`.
+    /// * `basis` - The RNS basis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::InvalidPolynomial` if the number of limbs does not match the
+    /// basis or the limbs have different lengths.
+    pub fn new(limbs: Vec<Polynomial>, basis: &RnsBasis) -> Result<Self, PolynomialError> {
+        if limbs.len() != basis.len() {
+            return Err(PolynomialError::InvalidPolynomial {
+                message: format!("Expected {} RNS limbs, found {}", basis.len(), limbs.len()),
+            });
+        }
+        if limbs
+            .windows(2)
+            .any(|w| w[0].coefficients().len() != w[1].coefficients().len())
+        {
+            return Err(PolynomialError::InvalidPolynomial {
+                message: "RNS limbs must have the same number of coefficients".to_string(),
+            });
+        }
+
+        let limbs = limbs
+            .iter()
+            .zip(basis.moduli())
+            .map(|(limb, q)| Polynomial::new(reduce_limb(limb.coefficients(), q)))
+            .collect();
+        Ok(Self { limbs })
+    }
+
+    /// Converts a polynomial to RNS form by reducing it modulo every limb.
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - The polynomial to convert.
+    /// * `basis` - The RNS basis.
+    pub fn from_polynomial(poly: &Polynomial, basis: &RnsBasis) -> Self {
+        Self {
+            limbs: basis
+                .moduli()
+                .iter()
+                .map(|q| Polynomial::new(reduce_limb(poly.coefficients(), q)))
+                .collect(),
+        }
+    }
+
+    /// Reconstructs the polynomial by the Chinese remainder theorem.
+    ///
+    /// # Arguments
+    ///
+    /// * `basis` - The RNS basis of this polynomial.
+    ///
+    /// # Returns
+    ///
+    /// The polynomial with coefficients centered modulo `Q`.
+    pub fn to_polynomial(&self, basis: &RnsBasis) -> Polynomial {
+        let coefficients: Vec<BigInt> = (0..self.num_coefficients())
+            .map(|j| {
+                let y = basis.scaled_residues(self.limbs.iter().map(|l| &l.coefficients()[j]));
+                y.iter()
+                    .zip(&basis.punctured)
+                    .map(|(y, p)| y * p)
+                    .sum::<BigInt>()
+                    .mod_floor(basis.product())
+            })
+            .collect();
+        Polynomial::new(coefficients).reduce_and_center(basis.product())
+    }
+
+    /// Returns the residue polynomials.
+    pub fn limbs(&self) -> &[Polynomial] {
+        &self.limbs
+    }
+
+    /// Returns the number of coefficients of each limb.
+    pub fn num_coefficients(&self) -> usize {
+        self.limbs.first().map_or(0, |l| l.coefficients().len())
+    }
+
+    /// Converts the polynomial to another basis with the fast (approximate) base conversion.
+    ///
+    /// For a coefficient `x` in `[0, Q)`, the result in each target limb `p_j` is
+    /// `[x + α * Q]_{p_j}` for some integer `0 <= α < k`, where `k` is the number of limbs of
+    /// `from`.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The basis of this polynomial.
+    /// * `to` - The target basis.
+    ///
+    /// # Returns
+    ///
+    /// The converted polynomial in the target basis.
+    pub fn fast_base_conversion(&self, from: &RnsBasis, to: &RnsBasis) -> RnsPolynomial {
+        let scaled: Vec<Vec<BigInt>> = (0..self.num_coefficients())
+            .map(|j| from.scaled_residues(self.limbs.iter().map(|l| &l.coefficients()[j])))
+            .collect();
+
+        let limbs = to
+            .moduli()
+            .iter()
+            .map(|p| {
+                let punctured: Vec<BigInt> = from.punctured.iter().map(|q| q % p).collect();
+                Polynomial::new(
+                    scaled
+                        .iter()
+                        .map(|y| {
+                            y.iter()
+                                .zip(&punctured)
+                                .map(|(y, q)| y * q)
+                                .sum::<BigInt>()
+                                .mod_floor(p)
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        RnsPolynomial { limbs }
+    }
+
+    /// Converts the polynomial exactly to another basis with the Shenoy–Kumaresan correction.
+    ///
+    /// The overflow `α` of [`Self::fast_base_conversion`] is recovered from the residue of the
+    /// polynomial modulo a redundant modulus `m_sk`, coprime with `Q` and at least the number
+    /// of limbs of `from`, and `α * Q` is subtracted in every target limb.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The basis of this polynomial.
+    /// * `redundant_modulus` - The redundant modulus `m_sk`.
+    /// * `redundant_limb` - The polynomial reduced modulo `m_sk`.
+    /// * `to` - The target basis.
+    ///
+    /// # Returns
+    ///
+    /// The polynomial with coefficients in `[0, Q)` represented exactly in the target basis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if `m_sk` is smaller than the number of limbs or
+    /// not coprime with `Q`.
+    /// Returns `PolynomialError::InvalidPolynomial` if the redundant limb has the wrong length.
+    pub fn shenoy_kumaresan_conversion(
+        &self,
+        from: &RnsBasis,
+        redundant_modulus: &BigInt,
+        redundant_limb: &Polynomial,
+        to: &RnsBasis,
+    ) -> Result<RnsPolynomial, PolynomialError> {
+        if redundant_modulus < &BigInt::from(from.len()) {
+            return Err(PolynomialError::ModulusError {
+                message: format!(
+                    "Redundant modulus {redundant_modulus} is smaller than the number of limbs {}",
+                    from.len()
+                ),
+            });
+        }
+        let product_inverse = (from.product() % redundant_modulus)
+            .modinv(redundant_modulus)
+            .ok_or_else(|| PolynomialError::ModulusError {
+                message: format!("Redundant modulus {redundant_modulus} is not coprime with Q"),
+            })?;
+        if redundant_limb.coefficients().len() != self.num_coefficients() {
+            return Err(PolynomialError::InvalidPolynomial {
+                message: "Redundant limb has the wrong number of coefficients".to_string(),
+            });
+        }
+
+        let redundant_basis = RnsBasis::new(vec![redundant_modulus.clone()])?;
+        let approx_sk = self.fast_base_conversion(from, &redundant_basis);
+        let alphas: Vec<BigInt> = approx_sk.limbs[0]
+            .coefficients()
+            .iter()
+            .zip(redundant_limb.coefficients())
+            .map(|(approx, exact)| {
+                ((approx - exact) * &product_inverse).mod_floor(redundant_modulus)
+            })
+            .collect();
+
+        let approx = self.fast_base_conversion(from, to);
+        let limbs = approx
+            .limbs
+            .iter()
+            .zip(to.moduli())
+            .map(|(limb, p)| {
+                let q_mod_p = from.product() % p;
+                Polynomial::new(
+                    limb.coefficients()
+                        .iter()
+                        .zip(&alphas)
+                        .map(|(x, alpha)| (x - alpha * &q_mod_p).mod_floor(p))
+                        .collect(),
+                )
+            })
+            .collect();
+
+        Ok(RnsPolynomial { limbs })
+    }
+
+    /// Computes `round(t * x / Q) mod t` coefficient-wise with the HPS method.
+    ///
+    /// Writing `t * x / Q = sum(x_i * t * θ_i / q_i) - t * α` with `θ_i = (Q/q_i)^{-1} mod q_i`,
+    /// each constant `t * θ_i / q_i` is split into an integer part `ω_i` and a remainder
+    /// `r_i / q_i`. The terms `x_i * ω_i` are accumulated exactly modulo `t`. Each product
+    /// `x_i * r_i` is divided by `q_i` first, so that only its fractional part `[x_i * r_i]_{q_i} / q_i`
+    /// in `[0, 1)` is accumulated in double precision, with an absolute error of about
+    /// `k * 2^-52` for `k` limbs regardless of their size. The result therefore matches the exact
+    /// computation unless `t * x / Q` lies within that error of a half-integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `basis` - The basis of this polynomial.
+    /// * `t` - The positive plaintext modulus.
+    ///
+    /// # Returns
+    ///
+    /// The polynomial with coefficients in `[0, t)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if `t` is not positive, and
+    /// `PolynomialError::InvalidPolynomial` if the number of limbs does not match the basis.
+    pub fn scale_and_round_hps(
+        &self,
+        basis: &RnsBasis,
+        t: &BigInt,
+    ) -> Result<Polynomial, PolynomialError> {
+        if t <= &BigInt::zero() {
+            return Err(PolynomialError::ModulusError {
+                message: format!("Plaintext modulus {t} must be positive"),
+            });
+        }
+
+        if self.limbs.len() != basis.len() {
+            return Err(PolynomialError::InvalidPolynomial {
+                message: format!(
+                    "Expected {} RNS limbs, found {}",
+                    basis.len(),
+                    self.limbs.len()
+                ),
+            });
+        }
+
+        // `t * θ_i = ω_i * q_i + r_i` with `0 <= r_i < q_i`.
+        let (integer_parts, remainders): (Vec<BigInt>, Vec<BigInt>) = basis
+            .inverses
+            .iter()
+            .zip(basis.moduli())
+            .map(|(theta, q)| {
+                let (integer, remainder) = (t * theta).div_mod_floor(q);
+                (integer.mod_floor(t), remainder)
+            })
+            .unzip();
+
+        let coefficients = (0..self.num_coefficients())
+            .map(|j| {
+                let mut integer = BigInt::zero();
+                let mut fraction = 0.0f64;
+                for (i, (limb, q)) in self.limbs.iter().zip(basis.moduli()).enumerate() {
+                    let x = &limb.coefficients()[j];
+                    let (carry, remainder) = (x * &remainders[i]).div_mod_floor(q);
+                    integer += x * &integer_parts[i] + carry;
+                    fraction += remainder.to_f64().unwrap_or(0.0) / q.to_f64().unwrap_or(f64::MAX);
+                }
+                (integer + BigInt::from(fraction.round() as i128)).mod_floor(t)
+            })
+            .collect();
+
+        Ok(Polynomial::new(coefficients))
+    }
+}
+
+/// Reduces coefficients to `[0, q)`.
+fn reduce_limb(coefficients: &[BigInt], q: &BigInt) -> Vec<BigInt> {
+    reduce_coefficients(&coefficients.iter().map(|c| c % q).collect::<Vec<_>>(), q)
+}
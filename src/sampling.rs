@@ -0,0 +1,66 @@
+//! Random polynomial samplers.
+//!
+//! These samplers produce polynomials with `degree + 1` coefficients in descending order,
+//! matching the representation used by [`Polynomial`]. They are generic over any `rand::Rng`
+//! so callers can plug in a seeded generator for reproducible test vectors.
+
+use crate::Polynomial;
+use crate::utils::reduce_and_center_coefficients;
+use num_bigint::{BigInt, RandBigInt};
+use num_traits::{One, Zero};
+use rand::Rng;
+
+/// Samples a polynomial with coefficients drawn uniformly from `Z_modulus`.
+///
+/// The coefficients are returned in centered form, i.e. in the range
+/// [−(modulus−1)/2, (modulus−1)/2].
+///
+/// # Arguments
+///
+/// * `degree` - The degree of the sampled polynomial.
+/// * `modulus` - The modulus defining the coefficient range.
+/// * `rng` - The random number generator.
+///
+/// # Panics
+///
+/// Panics if `modulus` is not positive.
+pub fn sample_uniform<R: Rng + ?Sized>(degree: usize, modulus: &BigInt, rng: &mut R) -> Polynomial {
+    let coefficients: Vec<BigInt> = (0..=degree)
+        .map(|_| rng.gen_bigint_range(&BigInt::zero(), modulus))
+        .collect();
+    Polynomial::new(reduce_and_center_coefficients(&coefficients, modulus))
+}
+
+/// Samples a polynomial with coefficients drawn uniformly from {-1, 0, 1}.
+///
+/// # Arguments
+///
+/// * `degree` - The degree of the sampled polynomial.
+/// * `rng` - The random number generator.
+pub fn sample_ternary<R: Rng + ?Sized>(degree: usize, rng: &mut R) -> Polynomial {
+    sample_bounded(degree, &BigInt::one(), rng)
+}
+
+/// Samples a polynomial with coefficients drawn uniformly from [-bound, bound].
+///
+/// This is a simple stand-in for a discrete Gaussian error distribution whose
+/// support has been truncated to `bound`.
+///
+/// # Arguments
+///
+/// * `degree` - The degree of the sampled polynomial.
+/// * `bound` - The (inclusive) bound on the absolute value of each coefficient.
+/// * `rng` - The random number generator.
+///
+/// # Panics
+///
+/// Panics if `bound` is negative.
+pub fn sample_bounded<R: Rng + ?Sized>(degree: usize, bound: &BigInt, rng: &mut R) -> Polynomial {
+    let low = -bound;
+    let high = bound + 1;
+    Polynomial::new(
+        (0..=degree)
+            .map(|_| rng.gen_bigint_range(&low, &high))
+            .collect(),
+    )
+}
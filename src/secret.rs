@@ -0,0 +1,145 @@
+//! A wrapper for secret polynomials that zeroizes its coefficients on drop.
+//!
+//! [`SecretPolynomial`] owns a [`Polynomial`] holding secret data such as a key or an error.
+//! Its coefficients are overwritten with zeros when it is dropped, they are omitted from `Debug`
+//! and `Display`, and the wrapper only implements `Serialize` and `Deserialize` when the
+//! `serde-secret` feature is enabled. The inner polynomial is reachable only through
+//! [`SecretPolynomial::expose_secret`] and [`SecretPolynomial::expose_secret_mut`], so every use
+//! of the secret is visible at the call site.
+//!
+//! Zeroization is best effort. The limbs of each `BigInt` are overwritten in place, but
+//! temporaries created by arithmetic on the exposed polynomial, and spare capacity left behind
+//! when a coefficient shrank, are not wiped. Secret-dependent timing is addressed separately by
+//! [`crate::ct`].
+
+use crate::Polynomial;
+use crate::errors::PolynomialError;
+use num_bigint::{BigInt, Sign};
+use std::fmt;
+use std::hint::black_box;
+use std::sync::atomic::{Ordering, compiler_fence};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Overwrites the limbs of a `BigInt` with zeros in place and sets it to zero.
+///
+/// `num-bigint` does not expose its limb buffer, so the limbs are overwritten through
+/// `assign_from_slice`, which reuses the existing allocation for a value of the same length.
+fn zeroize_bigint(value: &mut BigInt) {
+    let digits = value.bits().div_ceil(32) as usize;
+    value.assign_from_slice(Sign::Plus, &vec![0u32; digits]);
+    black_box(&*value);
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// A polynomial with secret coefficients, zeroized on drop.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde-secret",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct SecretPolynomial {
+    inner: Polynomial,
+}
+
+impl SecretPolynomial {
+    /// Wraps a polynomial, taking ownership so that no unprotected copy remains.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The secret polynomial.
+    pub fn new(inner: Polynomial) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the secret polynomial.
+    pub fn expose_secret(&self) -> &Polynomial {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the secret polynomial.
+    pub fn expose_secret_mut(&mut self) -> &mut Polynomial {
+        &mut self.inner
+    }
+
+    /// Returns the degree of the polynomial, which is not considered secret.
+    pub fn degree(&self) -> usize {
+        self.inner.degree()
+    }
+
+    /// Adds a polynomial to the secret.
+    pub fn add(&self, other: &Polynomial) -> Self {
+        Self::new(self.inner.add(other))
+    }
+
+    /// Subtracts a polynomial from the secret.
+    pub fn sub(&self, other: &Polynomial) -> Self {
+        Self::new(self.inner.sub(other))
+    }
+
+    /// Negates the secret.
+    pub fn neg(&self) -> Self {
+        Self::new(self.inner.neg())
+    }
+
+    /// Multiplies the secret by a polynomial.
+    pub fn mul(&self, other: &Polynomial) -> Self {
+        Self::new(self.inner.mul(other))
+    }
+
+    /// Multiplies the secret by a scalar.
+    pub fn scalar_mul(&self, scalar: &BigInt) -> Self {
+        Self::new(self.inner.scalar_mul(scalar))
+    }
+
+    /// Reduces and centers the coefficients of the secret modulo `modulus`.
+    pub fn reduce_and_center(&self, modulus: &BigInt) -> Self {
+        Self::new(self.inner.reduce_and_center(modulus))
+    }
+
+    /// Reduces the secret modulo a cyclotomic polynomial.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Polynomial::reduce_by_cyclotomic`].
+    pub fn reduce_by_cyclotomic(&self, cyclo: &[BigInt]) -> Result<Self, PolynomialError> {
+        self.inner.reduce_by_cyclotomic(cyclo).map(Self::new)
+    }
+}
+
+impl From<Polynomial> for SecretPolynomial {
+    fn from(inner: Polynomial) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl Zeroize for SecretPolynomial {
+    fn zeroize(&mut self) {
+        for coefficient in &mut self.inner.coefficients {
+            zeroize_bigint(coefficient);
+        }
+        self.inner.coefficients.clear();
+    }
+}
+
+impl Drop for SecretPolynomial {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretPolynomial {}
+
+impl fmt::Debug for SecretPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretPolynomial")
+            .field("degree", &self.degree())
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for SecretPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<secret polynomial of degree {}>", self.degree())
+    }
+}
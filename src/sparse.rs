@@ -0,0 +1,235 @@
+//! Sparse polynomial representation.
+//!
+//! A [`SparsePolynomial`] stores only its non-zero terms as `(exponent, coefficient)` pairs sorted
+//! by increasing exponent. This suits secrets of fixed Hamming weight `h` and monomial-heavy
+//! values: multiplying a sparse polynomial by a dense one modulo `x^N + 1` costs `O(h * N)`
+//! instead of the `O(N^2)` of dense multiplication.
+
+use crate::Polynomial;
+use crate::errors::PolynomialError;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use std::cmp::Ordering;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A polynomial stored as its non-zero terms, sorted by increasing exponent.
+///
+/// Deserialized terms are normalized as by [`SparsePolynomial::new`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(from = "RawSparsePolynomial")
+)]
+pub struct SparsePolynomial {
+    /// `(exponent, coefficient)` pairs with distinct exponents and non-zero coefficients.
+    terms: Vec<(usize, BigInt)>,
+}
+
+/// The serialized form of a [`SparsePolynomial`], whose terms may be in any order.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawSparsePolynomial {
+    terms: Vec<(usize, BigInt)>,
+}
+
+#[cfg(feature = "serde")]
+impl From<RawSparsePolynomial> for SparsePolynomial {
+    fn from(raw: RawSparsePolynomial) -> Self {
+        Self::new(raw.terms)
+    }
+}
+
+impl SparsePolynomial {
+    /// Creates a sparse polynomial from terms in any order.
+    ///
+    /// Terms with the same exponent are summed and zero terms are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - `(exponent, coefficient)` pairs.
+    pub fn new(mut terms: Vec<(usize, BigInt)>) -> Self {
+        terms.sort_by_key(|(exponent, _)| *exponent);
+        let mut merged: Vec<(usize, BigInt)> = Vec::with_capacity(terms.len());
+        for (exponent, coeff) in terms {
+            match merged.last_mut() {
+                Some((last, sum)) if *last == exponent => *sum += coeff,
+                _ => merged.push((exponent, coeff)),
+            }
+        }
+        merged.retain(|(_, coeff)| !coeff.is_zero());
+        Self { terms: merged }
+    }
+
+    /// Creates the zero polynomial.
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Creates the monomial `coeff * x^exponent`.
+    pub fn monomial(exponent: usize, coeff: BigInt) -> Self {
+        Self::new(vec![(exponent, coeff)])
+    }
+
+    /// Converts a dense polynomial, keeping only its non-zero coefficients.
+    pub fn from_polynomial(poly: &Polynomial) -> Self {
+        Self {
+            terms: poly
+                .coefficients()
+                .iter()
+                .rev()
+                .enumerate()
+                .filter(|(_, coeff)| !coeff.is_zero())
+                .map(|(exponent, coeff)| (exponent, coeff.clone()))
+                .collect(),
+        }
+    }
+
+    /// Converts to a dense polynomial with `degree + 1` coefficients.
+    pub fn to_polynomial(&self) -> Polynomial {
+        let mut ascending = vec![BigInt::zero(); self.degree() + 1];
+        for (exponent, coeff) in &self.terms {
+            ascending[*exponent] = coeff.clone();
+        }
+        Polynomial::from_ascending_coefficients(ascending)
+    }
+
+    /// Returns the non-zero terms, sorted by increasing exponent.
+    pub fn terms(&self) -> &[(usize, BigInt)] {
+        &self.terms
+    }
+
+    /// Returns the number of non-zero terms.
+    pub fn hamming_weight(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns the degree of the polynomial.
+    ///
+    /// The degree of a zero polynomial is 0.
+    pub fn degree(&self) -> usize {
+        self.terms.last().map_or(0, |(exponent, _)| *exponent)
+    }
+
+    /// Checks if the polynomial is zero.
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Adds two sparse polynomials by merging their terms in `O(h_1 + h_2)`.
+    pub fn add(&self, other: &Self) -> Self {
+        let mut terms = Vec::with_capacity(self.terms.len() + other.terms.len());
+        let (mut i, mut j) = (0, 0);
+        while i < self.terms.len() && j < other.terms.len() {
+            let ((ea, ca), (eb, cb)) = (&self.terms[i], &other.terms[j]);
+            match ea.cmp(eb) {
+                Ordering::Less => {
+                    terms.push((*ea, ca.clone()));
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    terms.push((*eb, cb.clone()));
+                    j += 1;
+                }
+                Ordering::Equal => {
+                    let sum = ca + cb;
+                    if !sum.is_zero() {
+                        terms.push((*ea, sum));
+                    }
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        terms.extend_from_slice(&self.terms[i..]);
+        terms.extend_from_slice(&other.terms[j..]);
+        Self { terms }
+    }
+
+    /// Negates all coefficients of the polynomial.
+    pub fn neg(&self) -> Self {
+        Self {
+            terms: self.terms.iter().map(|(e, c)| (*e, -c)).collect(),
+        }
+    }
+
+    /// Subtracts one sparse polynomial from another.
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    /// Multiplies each coefficient by a scalar.
+    pub fn scalar_mul(&self, scalar: &BigInt) -> Self {
+        if scalar.is_zero() {
+            return Self::zero();
+        }
+        Self {
+            terms: self.terms.iter().map(|(e, c)| (*e, c * scalar)).collect(),
+        }
+    }
+
+    /// Multiplies by a dense polynomial in `Z[x]/(x^n + 1)` in `O(h * n)`.
+    ///
+    /// Both operands are reduced modulo `x^n + 1` first. Each term `c * x^e` contributes a
+    /// negacyclic rotation of the dense polynomial by `e`, scaled by `c`; ternary coefficients
+    /// `±1` are applied as additions and subtractions without multiplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `dense` - The dense polynomial.
+    /// * `n` - The ring dimension; must be a power of two.
+    ///
+    /// # Returns
+    ///
+    /// A new polynomial with `n` coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::CyclotomicError` if `n` is not a power of two.
+    pub fn mul_negacyclic(
+        &self,
+        dense: &Polynomial,
+        n: usize,
+    ) -> Result<Polynomial, PolynomialError> {
+        let a = dense.negacyclic_ascending(n)?;
+        let two_n = 2 * n;
+
+        let mut out = vec![BigInt::zero(); n];
+        for (exponent, coeff) in &self.terms {
+            let shift = exponent % two_n;
+            // `Some(negative)` for a coefficient of ±1, applied without multiplication.
+            let unit = if coeff.is_one() {
+                Some(false)
+            } else if coeff.magnitude().is_one() {
+                Some(true)
+            } else {
+                None
+            };
+            for (i, a_i) in a.iter().enumerate() {
+                let target = (i + shift) % two_n;
+                // x^{target} = -x^{target - n} for target >= n.
+                let (index, wrapped) = if target < n {
+                    (target, false)
+                } else {
+                    (target - n, true)
+                };
+                match unit {
+                    Some(negative) if negative == wrapped => out[index] += a_i,
+                    Some(_) => out[index] -= a_i,
+                    None if wrapped => out[index] -= coeff * a_i,
+                    None => out[index] += coeff * a_i,
+                }
+            }
+        }
+
+        Ok(Polynomial::from_ascending_coefficients(out))
+    }
+}
+
+impl From<&Polynomial> for SparsePolynomial {
+    fn from(poly: &Polynomial) -> Self {
+        Self::from_polynomial(poly)
+    }
+}
@@ -0,0 +1,196 @@
+//! Fiat–Shamir transcripts over polynomials.
+//!
+//! A [`Transcript`] absorbs labelled messages into a running SHA-256 hash and squeezes
+//! challenges from it. Every absorbed item is framed with an operation tag and length-prefixed
+//! label and payload, so no two distinct sequences of operations produce the same hash input.
+//!
+//! Polynomials are absorbed using [`encode_polynomial`], a canonical encoding of the stored
+//! coefficient vector:
+//!
+//! ```text
+//! u64_le(number of coefficients) || for each coefficient, highest degree first:
+//!     sign byte (0 = non-negative, 1 = negative) || u64_le(magnitude length) || magnitude (LE)
+//! ```
+//!
+//! The length of the coefficient vector is part of the encoding, so `[0, 1]` and `[1]` absorb
+//! differently even though they represent the same polynomial; trim or pad polynomials to an
+//! agreed length before absorbing them.
+
+use crate::Polynomial;
+use crate::errors::PolynomialError;
+use crate::field::FieldModulus;
+use num_bigint::{BigInt, Sign};
+use num_integer::Integer;
+use num_traits::Zero;
+use sha2::{Digest, Sha256};
+
+/// Operation tag for the protocol label passed to [`Transcript::new`].
+const TAG_DOMAIN: u8 = 0;
+/// Operation tag for absorbed messages.
+const TAG_ABSORB: u8 = 1;
+/// Operation tag for squeezed challenges.
+const TAG_SQUEEZE: u8 = 2;
+/// Extra bits squeezed when sampling modulo `q`, making the bias at most `2^-128`.
+const CHALLENGE_SECURITY_BITS: u64 = 128;
+
+/// Encodes a `BigInt` as a sign byte followed by its length-prefixed little-endian magnitude.
+///
+/// # Arguments
+///
+/// * `x` - The integer to encode.
+///
+/// # Returns
+///
+/// The canonical byte encoding of `x`.
+pub fn encode_bigint(x: &BigInt) -> Vec<u8> {
+    let sign = if x.sign() == Sign::Minus { 1u8 } else { 0u8 };
+    let magnitude = if x.is_zero() {
+        Vec::new()
+    } else {
+        x.magnitude().to_bytes_le()
+    };
+
+    let mut out = Vec::with_capacity(9 + magnitude.len());
+    out.push(sign);
+    out.extend_from_slice(&(magnitude.len() as u64).to_le_bytes());
+    out.extend_from_slice(&magnitude);
+    out
+}
+
+/// Encodes a polynomial as its length-prefixed sequence of encoded coefficients.
+///
+/// # Arguments
+///
+/// * `poly` - The polynomial to encode.
+///
+/// # Returns
+///
+/// The canonical byte encoding of the stored coefficients, highest degree first.
+pub fn encode_polynomial(poly: &Polynomial) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&(poly.coefficients().len() as u64).to_le_bytes());
+    for coeff in poly.coefficients() {
+        out.extend_from_slice(&encode_bigint(coeff));
+    }
+    out
+}
+
+/// A Fiat–Shamir transcript backed by SHA-256.
+#[derive(Clone, Debug)]
+pub struct Transcript {
+    hasher: Sha256,
+}
+
+impl Transcript {
+    /// Creates a transcript bound to a protocol label.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - A domain-separation label identifying the protocol.
+    pub fn new(label: &[u8]) -> Self {
+        let mut transcript = Self {
+            hasher: Sha256::new(),
+        };
+        transcript.frame(TAG_DOMAIN, label, &[]);
+        transcript
+    }
+
+    /// Absorbs raw bytes under a label.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The domain-separation label of the message.
+    /// * `message` - The bytes to absorb.
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        self.frame(TAG_ABSORB, label, message);
+    }
+
+    /// Absorbs an integer under a label, using [`encode_bigint`].
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The domain-separation label of the integer.
+    /// * `x` - The integer to absorb.
+    pub fn append_bigint(&mut self, label: &[u8], x: &BigInt) {
+        self.append_message(label, &encode_bigint(x));
+    }
+
+    /// Absorbs a polynomial under a label, using [`encode_polynomial`].
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The domain-separation label of the polynomial.
+    /// * `poly` - The polynomial to absorb.
+    pub fn append_polynomial(&mut self, label: &[u8], poly: &Polynomial) {
+        self.append_message(label, &encode_polynomial(poly));
+    }
+
+    /// Squeezes challenge bytes under a label.
+    ///
+    /// The request (label and output length) is absorbed first, so subsequent challenges are
+    /// independent of this one.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The domain-separation label of the challenge.
+    /// * `out` - The buffer to fill with challenge bytes.
+    pub fn challenge_bytes(&mut self, label: &[u8], out: &mut [u8]) {
+        self.frame(TAG_SQUEEZE, label, &(out.len() as u64).to_le_bytes());
+        let seed = self.hasher.clone().finalize();
+
+        for (counter, chunk) in out.chunks_mut(32).enumerate() {
+            let block = Sha256::new()
+                .chain_update(seed)
+                .chain_update((counter as u64).to_le_bytes())
+                .finalize();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+    }
+
+    /// Squeezes a challenge uniformly distributed in `[0, modulus)`, up to a negligible bias.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The domain-separation label of the challenge.
+    /// * `modulus` - The positive modulus of the challenge space.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if `modulus` is not positive. The transcript is
+    /// left unchanged in that case.
+    pub fn challenge_bigint(
+        &mut self,
+        label: &[u8],
+        modulus: &BigInt,
+    ) -> Result<BigInt, PolynomialError> {
+        if modulus.sign() != Sign::Plus {
+            return Err(PolynomialError::ModulusError {
+                message: format!("Challenge modulus {modulus} must be positive"),
+            });
+        }
+        let len = (modulus.bits() + CHALLENGE_SECURITY_BITS).div_ceil(8) as usize;
+        let mut bytes = vec![0u8; len];
+        self.challenge_bytes(label, &mut bytes);
+        Ok(BigInt::from_bytes_le(Sign::Plus, &bytes).mod_floor(modulus))
+    }
+
+    /// Squeezes a challenge as a canonical element of a scalar field.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The domain-separation label of the challenge.
+    /// * `field` - The scalar field.
+    pub fn challenge_field(&mut self, label: &[u8], field: &FieldModulus) -> BigInt {
+        self.challenge_bigint(label, field.modulus())
+            .expect("field moduli are positive")
+    }
+
+    /// Absorbs an operation tag with a length-prefixed label and payload.
+    fn frame(&mut self, tag: u8, label: &[u8], payload: &[u8]) {
+        self.hasher.update([tag]);
+        self.hasher.update((label.len() as u64).to_le_bytes());
+        self.hasher.update(label);
+        self.hasher.update((payload.len() as u64).to_le_bytes());
+        self.hasher.update(payload);
+    }
+}
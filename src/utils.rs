@@ -1,8 +1,21 @@
 //! Utility functions for polynomial operations.
 
 use crate::Polynomial;
+use crate::errors::{PolynomialError, RangeViolation};
 use num_bigint::BigInt;
+use num_integer::Integer;
 use num_traits::Zero;
+use std::cmp::Ordering;
+
+/// Checks that a negacyclic ring dimension `n` of `Z[x]/(x^n + 1)` is a power of two.
+pub(crate) fn check_ring_dimension(n: usize) -> Result<(), PolynomialError> {
+    if !n.is_power_of_two() {
+        return Err(PolynomialError::CyclotomicError {
+            message: format!("Ring dimension {n} is not a power of two"),
+        });
+    }
+    Ok(())
+}
 
 /// Reduces a number modulo a prime modulus and centers it.
 ///
@@ -105,6 +118,101 @@ pub fn reduce_scalar(x: &BigInt, modulus: &BigInt) -> BigInt {
     (x + modulus) % modulus
 }
 
+/// Rounding mode for divisions that produce a rational result.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to the nearest integer, with halves rounded away from zero.
+    #[default]
+    NearestTiesAway,
+    /// Round to the nearest integer, with halves rounded to the even neighbour.
+    NearestEven,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+}
+
+/// Divides two integers and rounds the exact rational quotient.
+///
+/// Unlike `BigInt` division, which truncates towards zero, the result is rounded according to
+/// `mode` and behaves consistently for negative values.
+///
+/// # Arguments
+///
+/// * `numerator` - The numerator.
+/// * `denominator` - The non-zero denominator.
+/// * `mode` - The rounding mode.
+///
+/// # Returns
+///
+/// The rounded quotient `numerator / denominator`.
+///
+/// # Panics
+///
+/// Panics if `denominator` is zero.
+pub fn divide_and_round_scalar(
+    numerator: &BigInt,
+    denominator: &BigInt,
+    mode: RoundingMode,
+) -> BigInt {
+    assert!(!denominator.is_zero(), "denominator cannot be zero");
+    let (numerator, denominator) = if denominator < &BigInt::zero() {
+        (-numerator, -denominator)
+    } else {
+        (numerator.clone(), denominator.clone())
+    };
+
+    // numerator = quotient * denominator + remainder, with 0 <= remainder < denominator.
+    let (quotient, remainder) = numerator.div_mod_floor(&denominator);
+    if remainder.is_zero() {
+        return quotient;
+    }
+
+    let doubled_remainder: BigInt = &remainder * 2;
+    let round_up = match mode {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceil => true,
+        RoundingMode::NearestTiesAway | RoundingMode::NearestEven => {
+            match doubled_remainder.cmp(&denominator) {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                // The exact value is quotient + 1/2.
+                Ordering::Equal => match mode {
+                    RoundingMode::NearestTiesAway => quotient >= BigInt::zero(),
+                    _ => quotient.is_odd(),
+                },
+            }
+        }
+    };
+
+    if round_up { quotient + 1 } else { quotient }
+}
+
+/// Computes `round(x * numerator / denominator)` exactly, using the given rounding mode.
+///
+/// # Arguments
+///
+/// * `x` - The value to scale.
+/// * `numerator` - The numerator of the scaling factor (e.g. `t`).
+/// * `denominator` - The non-zero denominator of the scaling factor (e.g. `q`).
+/// * `mode` - The rounding mode.
+///
+/// # Returns
+///
+/// The scaled and rounded value.
+///
+/// # Panics
+///
+/// Panics if `denominator` is zero.
+pub fn scale_and_round_scalar(
+    x: &BigInt,
+    numerator: &BigInt,
+    denominator: &BigInt,
+    mode: RoundingMode,
+) -> BigInt {
+    divide_and_round_scalar(&(x * numerator), denominator, mode)
+}
+
 /// Reduces a polynomial's coefficients within a polynomial ring defined by a cyclotomic polynomial and a modulus.
 ///
 /// This function performs two reductions on the polynomial represented by `coefficients`:
@@ -129,6 +237,65 @@ pub fn reduce_in_ring(coefficients: &mut Vec<BigInt>, cyclo: &[BigInt], modulus:
     reduce_and_center_coefficients_mut(coefficients, modulus);
 }
 
+/// Checks that a ring equation holds exactly over the integers.
+///
+/// Verifies `a = b + r1 * modulus + r2 * cyclo` over `Z[x]`, which is the relation proven in a
+/// circuit to show that `a ≡ b (mod cyclo, modulus)`. For a product `a = b * c`, pass `b.mul(&c)`
+/// as `b`, or use [`verify_ring_product`].
+///
+/// # Arguments
+///
+/// * `a` - The reduced polynomial.
+/// * `b` - The unreduced polynomial.
+/// * `r1` - The quotient by the modulus.
+/// * `r2` - The quotient by the cyclotomic polynomial.
+/// * `cyclo` - Coefficients of the cyclotomic polynomial.
+/// * `modulus` - The modulus.
+///
+/// # Returns
+///
+/// `true` if the identity holds, `false` otherwise.
+pub fn verify_ring_equation(
+    a: &Polynomial,
+    b: &Polynomial,
+    r1: &Polynomial,
+    r2: &Polynomial,
+    cyclo: &[BigInt],
+    modulus: &BigInt,
+) -> bool {
+    let rhs = b
+        .add(&r1.scalar_mul(modulus))
+        .add(&r2.mul(&Polynomial::new(cyclo.to_vec())));
+    a.sub(&rhs).is_zero()
+}
+
+/// Checks that `a = b * c + r1 * modulus + r2 * cyclo` holds exactly over `Z[x]`.
+///
+/// # Arguments
+///
+/// * `a` - The reduced product.
+/// * `b` - The first factor.
+/// * `c` - The second factor.
+/// * `r1` - The quotient by the modulus.
+/// * `r2` - The quotient by the cyclotomic polynomial.
+/// * `cyclo` - Coefficients of the cyclotomic polynomial.
+/// * `modulus` - The modulus.
+///
+/// # Returns
+///
+/// `true` if the identity holds, `false` otherwise.
+pub fn verify_ring_product(
+    a: &Polynomial,
+    b: &Polynomial,
+    c: &Polynomial,
+    r1: &Polynomial,
+    r2: &Polynomial,
+    cyclo: &[BigInt],
+    modulus: &BigInt,
+) -> bool {
+    verify_ring_equation(a, &b.mul(c), r1, r2, cyclo, modulus)
+}
+
 /// Reduces each element in the given slice of `BigInt` by the modulus `p`.
 ///
 /// This function takes a slice of `BigInt` coefficients and applies the modulus operation
@@ -184,6 +351,24 @@ pub fn reduce_coefficients_mut(coefficients: &mut [BigInt], p: &BigInt) {
     }
 }
 
+/// Computes a worst-case bound on the infinity norm of a product in `Z[x]/(x^N + 1)`.
+///
+/// For `a` and `b` in the ring, every coefficient of `a * b mod (x^N + 1)` is a signed sum of
+/// `N` products `a_i * b_j`, so `||a * b||∞ <= N * ||a||∞ * ||b||∞`.
+///
+/// # Arguments
+///
+/// * `n` - The ring dimension `N`.
+/// * `a_bound` - A bound on the infinity norm of `a`.
+/// * `b_bound` - A bound on the infinity norm of `b`.
+///
+/// # Returns
+///
+/// The bound `N * a_bound * b_bound`.
+pub fn ring_product_bound(n: usize, a_bound: &BigInt, b_bound: &BigInt) -> BigInt {
+    BigInt::from(n) * a_bound * b_bound
+}
+
 /// Checks if all coefficients in a vector are within a centered range.
 ///
 /// This function verifies that every coefficient in the input vector falls within
@@ -271,3 +456,225 @@ pub fn range_check_standard(vec: &[BigInt], bound: &BigInt, modulus: &BigInt) ->
             || (coeff >= &(modulus - bound) && coeff < modulus)
     })
 }
+
+/// A summary of a range check over a slice of coefficients.
+///
+/// Produced by [`range_report_centered`], [`range_report_standard`] and
+/// [`range_report_standard_2bounds`], which visit every coefficient instead of stopping at the
+/// first violation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeReport {
+    /// Every coefficient that failed the check, in index order.
+    pub violations: Vec<RangeViolation>,
+    /// The smallest coefficient observed, or `None` for an empty slice.
+    pub min: Option<BigInt>,
+    /// The largest coefficient observed, or `None` for an empty slice.
+    pub max: Option<BigInt>,
+}
+
+impl RangeReport {
+    /// Returns `true` if no coefficient failed the check.
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Converts the report into a result carrying every violation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::RangeViolations` if any coefficient failed the check.
+    pub fn into_result(self) -> Result<(), PolynomialError> {
+        match self.violations.first() {
+            None => Ok(()),
+            Some(first) => Err(PolynomialError::RangeViolations {
+                message: format!("{first} ({} violation(s) in total)", self.violations.len()),
+                violations: self.violations,
+            }),
+        }
+    }
+}
+
+/// Returns the violation for `coeff` if it lies outside the range, or `None` otherwise.
+fn range_violation(
+    index: usize,
+    coeff: &BigInt,
+    lower_bound: &BigInt,
+    upper_bound: &BigInt,
+    modulus: Option<&BigInt>,
+) -> Option<RangeViolation> {
+    let in_range = match modulus {
+        None => coeff >= lower_bound && coeff <= upper_bound,
+        Some(modulus) => {
+            (coeff >= &BigInt::zero() && coeff <= upper_bound)
+                || (coeff >= &(modulus + lower_bound) && coeff < modulus)
+        }
+    };
+
+    (!in_range).then(|| RangeViolation {
+        index,
+        value: coeff.clone(),
+        lower_bound: lower_bound.clone(),
+        upper_bound: upper_bound.clone(),
+        modulus: modulus.cloned(),
+    })
+}
+
+/// Returns an error for the first coefficient outside the range.
+fn check_range(
+    vec: &[BigInt],
+    lower_bound: &BigInt,
+    upper_bound: &BigInt,
+    modulus: Option<&BigInt>,
+) -> Result<(), PolynomialError> {
+    match vec
+        .iter()
+        .enumerate()
+        .find_map(|(i, coeff)| range_violation(i, coeff, lower_bound, upper_bound, modulus))
+    {
+        None => Ok(()),
+        Some(violation) => Err(PolynomialError::RangeViolations {
+            message: violation.to_string(),
+            violations: vec![violation],
+        }),
+    }
+}
+
+/// Collects every coefficient outside the range together with the observed extremes.
+fn range_report(
+    vec: &[BigInt],
+    lower_bound: &BigInt,
+    upper_bound: &BigInt,
+    modulus: Option<&BigInt>,
+) -> RangeReport {
+    RangeReport {
+        violations: vec
+            .iter()
+            .enumerate()
+            .filter_map(|(i, coeff)| range_violation(i, coeff, lower_bound, upper_bound, modulus))
+            .collect(),
+        min: vec.iter().min().cloned(),
+        max: vec.iter().max().cloned(),
+    }
+}
+
+/// Checks that all coefficients are within a centered range, reporting the first failure.
+///
+/// This is the reporting counterpart of [`range_check_centered`].
+///
+/// # Arguments
+///
+/// * `vec` - A slice of `BigInt` coefficients to check.
+/// * `lower_bound` - The minimum allowed value (inclusive).
+/// * `upper_bound` - The maximum allowed value (inclusive).
+///
+/// # Errors
+///
+/// Returns `PolynomialError::RangeViolations` describing the first coefficient out of range.
+pub fn check_range_centered(
+    vec: &[BigInt],
+    lower_bound: &BigInt,
+    upper_bound: &BigInt,
+) -> Result<(), PolynomialError> {
+    check_range(vec, lower_bound, upper_bound, None)
+}
+
+/// Checks standard range constraints with separate bounds, reporting the first failure.
+///
+/// This is the reporting counterpart of [`range_check_standard_2bounds`].
+///
+/// # Arguments
+///
+/// * `vec` - A slice of `BigInt` coefficients to check
+/// * `low_bound` - The lower bound for the negative range (typically negative)
+/// * `up_bound` - The upper bound for the positive range
+/// * `modulus` - The modulus used for wraparound calculations
+///
+/// # Errors
+///
+/// Returns `PolynomialError::RangeViolations` describing the first coefficient out of range.
+pub fn check_range_standard_2bounds(
+    vec: &[BigInt],
+    low_bound: &BigInt,
+    up_bound: &BigInt,
+    modulus: &BigInt,
+) -> Result<(), PolynomialError> {
+    check_range(vec, low_bound, up_bound, Some(modulus))
+}
+
+/// Checks symmetric standard range constraints, reporting the first failure.
+///
+/// This is the reporting counterpart of [`range_check_standard`].
+///
+/// # Arguments
+///
+/// * `vec` - A slice of `BigInt` coefficients to check
+/// * `bound` - The symmetric bound (both positive and negative)
+/// * `modulus` - The modulus used for wraparound calculations
+///
+/// # Errors
+///
+/// Returns `PolynomialError::RangeViolations` describing the first coefficient out of range.
+pub fn check_range_standard(
+    vec: &[BigInt],
+    bound: &BigInt,
+    modulus: &BigInt,
+) -> Result<(), PolynomialError> {
+    check_range(vec, &-bound, bound, Some(modulus))
+}
+
+/// Checks all coefficients against a centered range and summarizes the result.
+///
+/// # Arguments
+///
+/// * `vec` - A slice of `BigInt` coefficients to check.
+/// * `lower_bound` - The minimum allowed value (inclusive).
+/// * `upper_bound` - The maximum allowed value (inclusive).
+///
+/// # Returns
+///
+/// A [`RangeReport`] with every violation and the observed extremes.
+pub fn range_report_centered(
+    vec: &[BigInt],
+    lower_bound: &BigInt,
+    upper_bound: &BigInt,
+) -> RangeReport {
+    range_report(vec, lower_bound, upper_bound, None)
+}
+
+/// Checks all coefficients against standard range constraints with separate bounds and
+/// summarizes the result.
+///
+/// # Arguments
+///
+/// * `vec` - A slice of `BigInt` coefficients to check
+/// * `low_bound` - The lower bound for the negative range (typically negative)
+/// * `up_bound` - The upper bound for the positive range
+/// * `modulus` - The modulus used for wraparound calculations
+///
+/// # Returns
+///
+/// A [`RangeReport`] with every violation and the observed extremes.
+pub fn range_report_standard_2bounds(
+    vec: &[BigInt],
+    low_bound: &BigInt,
+    up_bound: &BigInt,
+    modulus: &BigInt,
+) -> RangeReport {
+    range_report(vec, low_bound, up_bound, Some(modulus))
+}
+
+/// Checks all coefficients against symmetric standard range constraints and summarizes the
+/// result.
+///
+/// # Arguments
+///
+/// * `vec` - A slice of `BigInt` coefficients to check
+/// * `bound` - The symmetric bound (both positive and negative)
+/// * `modulus` - The modulus used for wraparound calculations
+///
+/// # Returns
+///
+/// A [`RangeReport`] with every violation and the observed extremes.
+pub fn range_report_standard(vec: &[BigInt], bound: &BigInt, modulus: &BigInt) -> RangeReport {
+    range_report(vec, &-bound, bound, Some(modulus))
+}
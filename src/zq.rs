@@ -0,0 +1,413 @@
+//! Word-sized modular arithmetic for odd moduli below `2^62`.
+//!
+//! [`Zq`] holds a modulus `q` together with its precomputed Barrett and Montgomery constants and
+//! operates on residues stored as plain `u64` values in `[0, q)`. It provides:
+//!
+//! - Barrett reduction of double-word values, used by [`Zq::mul`].
+//! - Montgomery multiplication with `R = 2^64`, for repeated products in Montgomery form.
+//! - Shoup multiplication by a fixed operand with a precomputed quotient.
+//! - Lazy variants that leave results in `[0, 2q)` and defer the final correction.
+//!
+//! [`ZqPolynomial`] stores the coefficients of a polynomial modulo `q` as `u64` residues and
+//! converts losslessly to and from [`Polynomial`], whose coefficients are centered as by
+//! [`Polynomial::reduce_and_center`].
+
+use crate::errors::PolynomialError;
+use crate::{GenericPolynomial, Polynomial};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::ToPrimitive;
+
+/// The maximum bit length of a [`Zq`] or [`crate::Montgomery`] modulus.
+///
+/// Barrett reduction leaves a remainder below `3q`, which must fit in a `u64`.
+pub const MAX_MODULUS_BITS: u32 = 62;
+
+/// Returns the Montgomery constant `-q^{-1} mod 2^64` of an odd `q`.
+///
+/// Only the low word of a multi-word modulus matters, so [`crate::fixed::FixedModulus`] shares
+/// this with [`Zq`]. Newton iteration doubles the number of correct low bits each step.
+pub(crate) const fn neg_inverse(q: u64) -> u64 {
+    let mut inv: u64 = 1;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(q.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// An odd modulus `q < 2^62` with precomputed reduction constants.
+///
+/// Moduli of up to [`MAX_MODULUS_BITS`] bits are supported, so a 62-bit prime is accepted and
+/// a 63-bit prime is not.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Zq {
+    /// The modulus `q`.
+    q: u64,
+    /// The bit length `k` of `q`.
+    bits: u32,
+    /// The Barrett constant `floor(2^(2k) / q)`.
+    barrett: u64,
+    /// The Montgomery constant `-q^{-1} mod 2^64`.
+    neg_q_inv: u64,
+    /// `2^128 mod q`, used to convert into Montgomery form.
+    r2: u64,
+}
+
+impl Zq {
+    /// Creates a modulus and precomputes its reduction constants.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The modulus, which must be odd, greater than 1 and below `2^62`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if `q` is out of range or even.
+    pub fn new(q: u64) -> Result<Self, PolynomialError> {
+        if q < 3 || q % 2 == 0 || q >> MAX_MODULUS_BITS != 0 {
+            return Err(PolynomialError::ModulusError {
+                message: format!(
+                    "Modulus {q} must be odd, greater than 1 and below 2^{MAX_MODULUS_BITS}"
+                ),
+            });
+        }
+
+        Ok(Self::with_constants(q))
+    }
+
+    /// Precomputes the reduction constants of an odd modulus `q > 1` without validating it.
+    ///
+    /// This is the single place where the Barrett and Montgomery constants are computed; it is
+    /// shared with [`crate::Montgomery`], which evaluates it at compile time. Callers check that
+    /// `q` has at most [`MAX_MODULUS_BITS`] bits.
+    pub(crate) const fn with_constants(q: u64) -> Self {
+        let bits = u64::BITS - q.leading_zeros();
+        let barrett = ((1u128 << (2 * bits)) / q as u128) as u64;
+
+        let r2 = ((u128::MAX % q as u128 + 1) % q as u128) as u64;
+
+        Self {
+            q,
+            bits,
+            barrett,
+            neg_q_inv: neg_inverse(q),
+            r2,
+        }
+    }
+
+    /// Returns the modulus `q`.
+    pub fn modulus(&self) -> u64 {
+        self.q
+    }
+
+    /// Reduces a single-word value modulo `q`.
+    pub fn reduce(&self, a: u64) -> u64 {
+        if 2 * self.bits >= u64::BITS {
+            self.reduce_u128(u128::from(a))
+        } else {
+            a % self.q
+        }
+    }
+
+    /// Reduces a value `a < 2^(2k)`, where `k` is the bit length of `q`, by Barrett reduction.
+    ///
+    /// Every product of two residues satisfies this bound.
+    pub fn reduce_u128(&self, a: u128) -> u64 {
+        debug_assert!(a >> (2 * self.bits) == 0, "Barrett input out of range");
+        let estimate = ((a >> (self.bits - 1)) * u128::from(self.barrett)) >> (self.bits + 1);
+        let mut r = (a - estimate * u128::from(self.q)) as u64;
+        // The quotient estimate is at most two below the true quotient.
+        while r >= self.q {
+            r -= self.q;
+        }
+        r
+    }
+
+    /// Reduces a value in `[0, 2q)` to `[0, q)`.
+    pub fn reduce_lazy(&self, a: u64) -> u64 {
+        debug_assert!(a < 2 * self.q);
+        if a >= self.q { a - self.q } else { a }
+    }
+
+    /// Reduces a signed integer of any size modulo `q`.
+    pub fn reduce_bigint(&self, a: &BigInt) -> u64 {
+        a.mod_floor(&BigInt::from(self.q))
+            .to_u64()
+            .expect("residue modulo a u64 modulus fits in u64")
+    }
+
+    /// Returns the centered representative of `a` in `[-(q-1)/2, (q-1)/2]`.
+    pub fn center(&self, a: u64) -> i64 {
+        if a > self.q / 2 {
+            a as i64 - self.q as i64
+        } else {
+            a as i64
+        }
+    }
+
+    /// Returns `a + b mod q` for residues `a, b`.
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        self.reduce_lazy(a + b)
+    }
+
+    /// Returns `a - b mod q` for residues `a, b`.
+    pub fn sub(&self, a: u64, b: u64) -> u64 {
+        self.reduce_lazy(a + self.q - b)
+    }
+
+    /// Returns `-a mod q` for a residue `a`.
+    pub fn neg(&self, a: u64) -> u64 {
+        if a == 0 { 0 } else { self.q - a }
+    }
+
+    /// Returns `a * b mod q` for residues `a, b`, using Barrett reduction.
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce_u128(u128::from(a) * u128::from(b))
+    }
+
+    /// Returns `a^e mod q` for a residue `a`.
+    pub fn pow(&self, a: u64, mut e: u64) -> u64 {
+        let mut base = self.to_montgomery(a);
+        let mut acc = self.to_montgomery(1);
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = self.mul_montgomery(acc, base);
+            }
+            base = self.mul_montgomery(base, base);
+            e >>= 1;
+        }
+        self.from_montgomery(acc)
+    }
+
+    /// Returns the inverse of a residue `a`, or `None` if it is not invertible modulo `q`.
+    pub fn inv(&self, a: u64) -> Option<u64> {
+        BigInt::from(a)
+            .modinv(&BigInt::from(self.q))
+            .and_then(|inv| inv.to_u64())
+    }
+
+    /// Montgomery reduction: returns `t * 2^-64 mod q` for `t < q * 2^64`.
+    fn redc(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.neg_q_inv);
+        let u = ((t + u128::from(m) * u128::from(self.q)) >> 64) as u64;
+        self.reduce_lazy(u)
+    }
+
+    /// Converts a residue to Montgomery form `a * 2^64 mod q`.
+    pub fn to_montgomery(&self, a: u64) -> u64 {
+        self.redc(u128::from(a) * u128::from(self.r2))
+    }
+
+    /// Converts a residue out of Montgomery form.
+    pub fn from_montgomery(&self, a: u64) -> u64 {
+        self.redc(u128::from(a))
+    }
+
+    /// Multiplies two residues in Montgomery form, returning the product in Montgomery form.
+    pub fn mul_montgomery(&self, a: u64, b: u64) -> u64 {
+        self.redc(u128::from(a) * u128::from(b))
+    }
+
+    /// Precomputes the Shoup quotient `floor(w * 2^64 / q)` of a fixed residue `w`.
+    pub fn shoup(&self, w: u64) -> u64 {
+        debug_assert!(w < self.q);
+        ((u128::from(w) << 64) / u128::from(self.q)) as u64
+    }
+
+    /// Returns `a * w mod q` in `[0, 2q)`, given the Shoup quotient `w_shoup` of `w`.
+    ///
+    /// `a` may be any `u64`, which allows chaining lazy results without correction.
+    pub fn mul_shoup_lazy(&self, a: u64, w: u64, w_shoup: u64) -> u64 {
+        let quotient = ((u128::from(a) * u128::from(w_shoup)) >> 64) as u64;
+        a.wrapping_mul(w)
+            .wrapping_sub(quotient.wrapping_mul(self.q))
+    }
+
+    /// Returns `a * w mod q`, given the Shoup quotient `w_shoup` of `w`.
+    pub fn mul_shoup(&self, a: u64, w: u64, w_shoup: u64) -> u64 {
+        self.reduce_lazy(self.mul_shoup_lazy(a, w, w_shoup))
+    }
+}
+
+/// A polynomial with coefficients in `Z_q`, stored as `u64` residues in descending order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZqPolynomial {
+    /// The coefficient modulus.
+    zq: Zq,
+    /// Residues in `[0, q)`, highest degree first.
+    residues: GenericPolynomial<u64>,
+}
+
+impl ZqPolynomial {
+    /// Creates a polynomial from coefficients in descending order, reducing them modulo `q`.
+    ///
+    /// # Arguments
+    ///
+    /// * `coefficients` - Coefficients in descending order of degree.
+    /// * `zq` - The coefficient modulus.
+    pub fn new(coefficients: Vec<u64>, zq: Zq) -> Self {
+        Self::from_residues(
+            GenericPolynomial::new(coefficients).map(|&c| zq.reduce(c)),
+            zq,
+        )
+    }
+
+    /// Wraps residues that are already reduced modulo `q`.
+    fn from_residues(residues: GenericPolynomial<u64>, zq: Zq) -> Self {
+        Self { zq, residues }
+    }
+
+    /// Creates the zero polynomial of specified degree.
+    pub fn zero(degree: usize, zq: Zq) -> Self {
+        Self::from_residues(GenericPolynomial::zero(degree), zq)
+    }
+
+    /// Reduces a `BigInt` polynomial modulo `q`.
+    ///
+    /// # Arguments
+    ///
+    /// * `poly` - The polynomial to convert.
+    /// * `zq` - The coefficient modulus.
+    pub fn from_polynomial(poly: &Polynomial, zq: Zq) -> Self {
+        Self::from_residues(poly.map(|c| zq.reduce_bigint(c)), zq)
+    }
+
+    /// Converts the polynomial to a `BigInt` polynomial with centered coefficients.
+    ///
+    /// For every polynomial `p`, `ZqPolynomial::from_polynomial(p, zq).to_polynomial()` equals
+    /// `p.reduce_and_center(q)`.
+    pub fn to_polynomial(&self) -> Polynomial {
+        self.residues.map(|&c| BigInt::from(self.zq.center(c)))
+    }
+
+    /// Returns the coefficient modulus.
+    pub fn zq(&self) -> &Zq {
+        &self.zq
+    }
+
+    /// Returns the residues in `[0, q)` as a polynomial over `u64`.
+    pub fn residues(&self) -> &GenericPolynomial<u64> {
+        &self.residues
+    }
+
+    /// Returns the residues in descending order of degree.
+    pub fn coefficients(&self) -> &[u64] {
+        self.residues.coefficients()
+    }
+
+    /// Returns the degree of the polynomial.
+    ///
+    /// The degree of a zero polynomial is 0.
+    pub fn degree(&self) -> usize {
+        self.residues.degree()
+    }
+
+    /// Checks if the polynomial is zero.
+    pub fn is_zero(&self) -> bool {
+        self.residues.is_zero()
+    }
+
+    /// Checks that two polynomials share a modulus.
+    fn check_modulus(&self, other: &Self) -> Result<(), PolynomialError> {
+        if self.zq != other.zq {
+            return Err(PolynomialError::ModulusError {
+                message: format!(
+                    "Moduli {} and {} do not match",
+                    self.zq.modulus(),
+                    other.zq.modulus()
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Adds two polynomials.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if the moduli differ.
+    pub fn add(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.check_modulus(other)?;
+        Ok(Self::from_residues(
+            self.residues
+                .zip_with(&other.residues, &0, |&a, &b| self.zq.add(a, b)),
+            self.zq,
+        ))
+    }
+
+    /// Subtracts `other` from `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if the moduli differ.
+    pub fn sub(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.check_modulus(other)?;
+        Ok(Self::from_residues(
+            self.residues
+                .zip_with(&other.residues, &0, |&a, &b| self.zq.sub(a, b)),
+            self.zq,
+        ))
+    }
+
+    /// Negates all coefficients of the polynomial.
+    pub fn neg(&self) -> Self {
+        Self::from_residues(self.residues.map(|&c| self.zq.neg(c)), self.zq)
+    }
+
+    /// Multiplies each coefficient by a scalar using Shoup multiplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `scalar` - The scalar, reduced modulo `q` before use.
+    pub fn scalar_mul(&self, scalar: u64) -> Self {
+        let w = self.zq.reduce(scalar);
+        let w_shoup = self.zq.shoup(w);
+        Self::from_residues(
+            self.residues.map(|&c| self.zq.mul_shoup(c, w, w_shoup)),
+            self.zq,
+        )
+    }
+
+    /// Multiplies two polynomials using the naive algorithm.
+    ///
+    /// Products are accumulated in 128 bits and kept below `q^2` by a conditional subtraction,
+    /// so each output coefficient needs a single Barrett reduction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolynomialError::ModulusError` if the moduli differ.
+    pub fn mul(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.check_modulus(other)?;
+        let (a, b) = (self.coefficients(), other.coefficients());
+        if a.is_empty() || b.is_empty() {
+            return Ok(Self::zero(0, self.zq));
+        }
+
+        let q_squared = u128::from(self.zq.q) * u128::from(self.zq.q);
+        let mut product = vec![0u128; a.len() + b.len() - 1];
+        for (i, &a) in a.iter().enumerate() {
+            for (j, &b) in b.iter().enumerate() {
+                let acc = &mut product[i + j];
+                *acc += u128::from(a) * u128::from(b);
+                if *acc >= q_squared {
+                    *acc -= q_squared;
+                }
+            }
+        }
+
+        Ok(Self::from_residues(
+            GenericPolynomial::new(product).map(|&c| self.zq.reduce_u128(c)),
+            self.zq,
+        ))
+    }
+
+    /// Evaluates the polynomial at a point using Horner's method.
+    pub fn evaluate(&self, x: u64) -> u64 {
+        let x = self.zq.reduce(x);
+        self.coefficients()
+            .iter()
+            .fold(0, |acc, &c| self.zq.add(self.zq.mul(acc, x), c))
+    }
+}
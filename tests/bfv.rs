@@ -0,0 +1,132 @@
+use bigint_poly::Polynomial;
+use bigint_poly::bfv::*;
+use bigint_poly::errors::PolynomialError;
+use num_bigint::BigInt;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_params() -> BfvParameters {
+        BfvParameters::new(
+            16,
+            BigInt::from(17),
+            BigInt::from(1152921504606846883u64),
+            BigInt::from(3),
+        )
+        .unwrap()
+    }
+
+    fn message(values: &[i64]) -> Polynomial {
+        Polynomial::new(values.iter().map(|&v| BigInt::from(v)).collect())
+    }
+
+    fn padded(poly: &Polynomial, n: usize) -> Vec<BigInt> {
+        let mut coefficients = vec![BigInt::from(0); n - poly.coefficients().len()];
+        coefficients.extend_from_slice(poly.coefficients());
+        coefficients
+    }
+
+    #[test]
+    fn test_parameter_validation() {
+        let q = BigInt::from(1152921504606846883u64);
+        assert!(matches!(
+            BfvParameters::new(12, BigInt::from(17), q.clone(), BigInt::from(3)),
+            Err(PolynomialError::CyclotomicError { .. })
+        ));
+        assert!(matches!(
+            BfvParameters::new(16, BigInt::from(1), q, BigInt::from(3)),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+        assert!(matches!(
+            BfvParameters::new(16, BigInt::from(17), BigInt::from(17), BigInt::from(3)),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let params = test_params();
+        let mut rng = StdRng::seed_from_u64(42);
+        let (sk, pk) = keygen(&params, &mut rng);
+
+        let m = message(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        let (ct, _) = encrypt(&params, &pk, &m, &mut rng).unwrap();
+        assert_eq!(decrypt(&params, &sk, &ct), m);
+    }
+
+    #[test]
+    fn test_encryption_witness_identity() {
+        let params = test_params();
+        let mut rng = StdRng::seed_from_u64(7);
+        let (_, pk) = keygen(&params, &mut rng);
+
+        let m = message(&[3, 0, 16, 1]);
+        let (ct, w) = encrypt(&params, &pk, &m, &mut rng).unwrap();
+        let q = Polynomial::constant(params.ciphertext_modulus().clone());
+        let cyclo = Polynomial::new(params.cyclotomic());
+
+        let ct0 = pk
+            .pk0
+            .mul(&w.u)
+            .add(&w.e0)
+            .add(&w.scaled_message)
+            .add(&w.r2.mul(&cyclo))
+            .add(&w.r1.mul(&q))
+            .trim_leading_zeros();
+        let ct1 = pk
+            .pk1
+            .mul(&w.u)
+            .add(&w.e1)
+            .add(&w.p2.mul(&cyclo))
+            .add(&w.p1.mul(&q))
+            .trim_leading_zeros();
+
+        assert_eq!(padded(&ct0, 16), ct.ct0.coefficients());
+        assert_eq!(padded(&ct1, 16), ct.ct1.coefficients());
+    }
+
+    #[test]
+    fn test_homomorphic_addition() {
+        let params = test_params();
+        let mut rng = StdRng::seed_from_u64(1);
+        let (sk, pk) = keygen(&params, &mut rng);
+
+        let (a, _) = encrypt(&params, &pk, &message(&[16, 5, 1]), &mut rng).unwrap();
+        let (b, _) = encrypt(&params, &pk, &message(&[2, 5, 0]), &mut rng).unwrap();
+        let sum = add(&params, &a, &b);
+
+        let mut expected = vec![BigInt::from(0); 13];
+        expected.extend([1, 10, 1].map(BigInt::from));
+        assert_eq!(decrypt(&params, &sk, &sum).coefficients(), expected);
+    }
+
+    #[test]
+    fn test_plaintext_multiplication() {
+        let params = test_params();
+        let mut rng = StdRng::seed_from_u64(3);
+        let (sk, pk) = keygen(&params, &mut rng);
+
+        // (x + 2) * (x + 3) = x^2 + 5x + 6
+        let (ct, _) = encrypt(&params, &pk, &message(&[1, 2]), &mut rng).unwrap();
+        let product = mul_plain(&params, &ct, &message(&[1, 3])).unwrap();
+
+        let mut expected = vec![BigInt::from(0); 13];
+        expected.extend([1, 5, 6].map(BigInt::from));
+        assert_eq!(decrypt(&params, &sk, &product).coefficients(), expected);
+    }
+
+    #[test]
+    fn test_plaintext_too_large() {
+        let params = test_params();
+        let mut rng = StdRng::seed_from_u64(0);
+        let (_, pk) = keygen(&params, &mut rng);
+        let m = Polynomial::zero(16);
+        assert!(matches!(
+            encrypt(&params, &pk, &m, &mut rng),
+            Err(PolynomialError::InvalidPolynomial { .. })
+        ));
+    }
+}
@@ -0,0 +1,102 @@
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::{Coefficient, GenericPolynomial, Montgomery};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+mod common;
+use common::poly;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const Q: u64 = 0x3fff_ffff_ffff_ffc7; // 2^62 - 57, the largest supported prime
+    type Zq = Montgomery<Q>;
+
+    #[test]
+    fn test_primitive_arithmetic_matches_bigint() {
+        let a = GenericPolynomial::new(vec![3i64, -2, 5]);
+        let b = GenericPolynomial::new(vec![1i64, 4]);
+
+        assert_eq!(a.add(&b).to_bigint_polynomial(), poly(&[3, -1, 9]));
+        assert_eq!(a.sub(&b).to_bigint_polynomial(), poly(&[3, -3, 1]));
+        assert_eq!(
+            a.mul(&b).to_bigint_polynomial(),
+            poly(&[3, -2, 5]).mul(&poly(&[1, 4]))
+        );
+        assert_eq!(a.evaluate(&2), 12 - 4 + 5);
+        assert_eq!(a.scalar_mul(&-1), a.neg());
+    }
+
+    #[test]
+    fn test_unsigned_coefficients() {
+        let a = GenericPolynomial::new(vec![2u64, 0, 1]);
+        let b = GenericPolynomial::new(vec![BigUint::from(2u32), BigUint::zero(), BigUint::one()]);
+
+        assert_eq!(a.mul(&a).coefficients(), &[4, 0, 4, 0, 1]);
+        assert_eq!(
+            b.mul(&b).to_bigint_polynomial(),
+            a.mul(&a).to_bigint_polynomial()
+        );
+        assert_eq!(a.to_string(), "2x^2 + 1");
+    }
+
+    #[test]
+    fn test_try_from_polynomial() {
+        let p = poly(&[1, -1, 7]);
+
+        assert_eq!(
+            GenericPolynomial::<i128>::try_from_polynomial(&p)
+                .unwrap()
+                .to_bigint_polynomial(),
+            p
+        );
+        assert!(matches!(
+            GenericPolynomial::<u64>::try_from_polynomial(&p),
+            Err(PolynomialError::ArithmeticError { .. })
+        ));
+        assert!(
+            GenericPolynomial::<i64>::try_from_polynomial(
+                &poly(&[1]).scalar_mul(&(BigInt::from(1) << 70))
+            )
+            .is_err()
+        );
+
+        // Modular coefficients reduce instead of failing.
+        let reduced = GenericPolynomial::<Zq>::try_from_polynomial(&p).unwrap();
+        assert_eq!(reduced.coefficients()[1].value(), Q - 1);
+    }
+
+    #[test]
+    fn test_montgomery_arithmetic() {
+        let a = Zq::new(Q - 1);
+        let b = Zq::new(123_456_789);
+
+        assert_eq!(Zq::one().value(), 1);
+        assert_eq!((a * a).value(), 1);
+        assert_eq!((a + b).value(), 123_456_788);
+        assert_eq!((b - b), Zq::zero());
+        assert_eq!((-a).value(), 1);
+
+        let x = 0x1234_5678_9abc_def0u64 % Q;
+        let y = 0x0fed_cba9_8765_4321u64 % Q;
+        let expected = (u128::from(x) * u128::from(y) % u128::from(Q)) as u64;
+        assert_eq!((Zq::new(x) * Zq::new(y)).value(), expected);
+        assert_eq!(Zq::new(x).to_bigint(), BigInt::from(x));
+        assert_eq!(Zq::from_bigint(&BigInt::from(-1)), Some(a));
+    }
+
+    #[test]
+    fn test_montgomery_polynomial_matches_bigint() {
+        let a = poly(&[5, -3, 0, 11]);
+        let b = poly(&[-7, 2, 9]);
+        let modulus = BigInt::from(Q);
+
+        let a_q = GenericPolynomial::<Zq>::try_from_polynomial(&a).unwrap();
+        let b_q = GenericPolynomial::<Zq>::try_from_polynomial(&b).unwrap();
+
+        let expected = a.mul(&b).sub(&b).reduce_and_center(&modulus);
+        let actual = a_q.mul(&b_q).sub(&b_q).to_bigint_polynomial();
+        assert_eq!(actual.reduce_and_center(&modulus), expected);
+    }
+}
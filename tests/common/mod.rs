@@ -0,0 +1,16 @@
+//! Fixtures shared by the integration tests.
+
+#![allow(dead_code)]
+
+use bigint_poly::Polynomial;
+use num_bigint::BigInt;
+
+/// Builds a polynomial from small coefficients in descending order of degree.
+pub fn poly(values: &[i64]) -> Polynomial {
+    Polynomial::new(values.iter().map(|&v| BigInt::from(v)).collect())
+}
+
+/// Builds a polynomial from small coefficients in ascending order of degree.
+pub fn ascending(values: &[i64]) -> Polynomial {
+    Polynomial::from_ascending_coefficients(values.iter().map(|&v| BigInt::from(v)).collect())
+}
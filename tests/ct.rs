@@ -0,0 +1,246 @@
+use bigint_poly::Polynomial;
+use bigint_poly::ct::*;
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::fixed::*;
+use num_bigint::{BigInt, RandBigInt};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::hint::black_box;
+use std::time::Instant;
+use subtle::{ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modulus() -> (BigInt, FixedModulus<4>) {
+        let q = (BigInt::from(1) << 255) - 19;
+        let m = FixedModulus::new(&q).unwrap();
+        (q, m)
+    }
+
+    fn random_residue(rng: &mut StdRng, q: &BigInt, m: &FixedModulus<4>) -> FixedUint<4> {
+        m.reduce_bigint(&rng.gen_bigint_range(&BigInt::from(0), q))
+    }
+
+    #[test]
+    fn test_comparisons_and_selection() {
+        let a = U256::from_limbs([5, 0, 0, 1]);
+        let b = U256::from_limbs([7, 0, 0, 0]);
+
+        assert!(bool::from(a.ct_gt(&b)));
+        assert!(bool::from(b.ct_lt(&a)));
+        assert!(!bool::from(a.ct_gt(&a)));
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+        assert_eq!(U256::conditional_select(&a, &b, 0.into()), a);
+        assert_eq!(U256::conditional_select(&a, &b, 1.into()), b);
+    }
+
+    #[test]
+    fn test_ct_operations_match_variable_time() {
+        let (q, m) = modulus();
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut samples = vec![FixedUint::ZERO, m.reduce_bigint(&BigInt::from(-1))];
+        samples.extend((0..50).map(|_| random_residue(&mut rng, &q, &m)));
+
+        for a in &samples {
+            for b in &samples {
+                assert_eq!(m.ct_add(a, b), m.add(a, b));
+                assert_eq!(m.ct_sub(a, b), m.sub(a, b));
+                assert_eq!(m.ct_mul(a, b), m.mul(a, b));
+                assert_eq!(m.ct_mul_montgomery(a, b), m.mul_montgomery(a, b));
+            }
+            assert_eq!(m.ct_neg(a), m.neg(a));
+            assert_eq!(m.ct_center(a).to_bigint(), m.center(a));
+        }
+    }
+
+    #[test]
+    fn test_centered_range_check() {
+        let (q, m) = modulus();
+        let bound = U256::from_u64(3);
+        let p = CtPolynomial::from_centered(&[3, -3, 0, 1], m);
+        assert!(bool::from(p.check_range_centered(&bound)));
+        assert!(!bool::from(p.check_range_centered(&U256::from_u64(2))));
+
+        let p = CtPolynomial::from_centered(&[-4, 0], m);
+        assert!(!bool::from(p.check_range_centered(&bound)));
+        assert_eq!(
+            p.to_polynomial(),
+            Polynomial::new(vec![BigInt::from(-4), BigInt::from(0)])
+        );
+        assert_eq!(p.coefficients()[0], m.reduce_bigint(&(q - BigInt::from(4))));
+    }
+
+    #[test]
+    fn test_polynomial_matches_fixed_polynomial() {
+        let (q, m) = modulus();
+        let secret = CtPolynomial::from_centered(&[1, 0, -1, -1, 1, i64::MIN], m);
+        let public = Polynomial::new((0..6).map(|i| (BigInt::from(1) << (40 * i)) - i).collect());
+        let a = CtPolynomial::from_polynomial(&public, m);
+
+        let expected_secret = secret.to_polynomial();
+        assert_eq!(
+            secret.mul(&a).unwrap().to_polynomial(),
+            expected_secret.mul(&public).reduce_and_center(&q)
+        );
+        assert_eq!(
+            secret.add(&a).unwrap().to_polynomial(),
+            expected_secret.add(&public).reduce_and_center(&q)
+        );
+        assert_eq!(
+            secret.sub(&a).unwrap().to_polynomial(),
+            expected_secret.sub(&public).reduce_and_center(&q)
+        );
+        assert_eq!(secret.neg().to_polynomial(), expected_secret.neg());
+        assert!(bool::from(secret.ct_eq(&secret.neg().neg())));
+        assert!(!bool::from(secret.ct_eq(&a)));
+    }
+
+    #[test]
+    fn test_incompatible_polynomials() {
+        let (_, m) = modulus();
+        let other = FixedModulus::<4>::new(&((BigInt::from(1) << 250) + 1)).unwrap();
+        let a = CtPolynomial::from_centered(&[1, 2], m);
+
+        assert!(matches!(
+            a.add(&CtPolynomial::from_centered(&[1, 2, 3], m)),
+            Err(PolynomialError::InvalidPolynomial { .. })
+        ));
+        assert!(matches!(
+            a.mul(&CtPolynomial::from_centered(&[1], other)),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_debug_redacts_coefficients() {
+        let (_, m) = modulus();
+        let secret = CtPolynomial::from_centered(&[123456789, -987654321, 42], m);
+
+        let debug = format!("{secret:?}");
+        for c in secret.coefficients() {
+            assert!(!debug.contains(&format!("{c:?}")));
+        }
+        assert!(!debug.contains("123456789"));
+        assert!(!debug.contains("42"));
+        assert_eq!(
+            debug,
+            format!("CtPolynomial {{ modulus: {:?}, len: 3, .. }}", m.modulus())
+        );
+
+        let centered = format!("{:?}", secret.centered_coefficients());
+        assert!(!centered.contains("123456789"));
+        assert!(!centered.contains("42"));
+        assert_eq!(
+            centered,
+            "[CenteredResidue { .. }, CenteredResidue { .. }, CenteredResidue { .. }]"
+        );
+    }
+
+    /// Welch's t-statistic between two timing samples.
+    fn welch_t(a: &[f64], b: &[f64]) -> f64 {
+        let stats = |x: &[f64]| {
+            let n = x.len() as f64;
+            let mean = x.iter().sum::<f64>() / n;
+            let var = x.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+            (mean, var, n)
+        };
+        let (ma, va, na) = stats(a);
+        let (mb, vb, nb) = stats(b);
+        (ma - mb) / (va / na + vb / nb).sqrt()
+    }
+
+    /// Runs a dudect-style test: prepares inputs from a fixed class and a random class in random
+    /// order, times `op` on each, crops outliers above the 90th percentile and returns the
+    /// absolute Welch t-statistic. Values above 4.5 indicate a timing leak.
+    fn dudect<I>(
+        measurements: usize,
+        fixed: impl Fn() -> I,
+        random: impl Fn(&mut StdRng) -> I,
+        op: impl Fn(&I),
+    ) -> f64 {
+        let mut rng = StdRng::seed_from_u64(0xdedec7);
+        let inputs: Vec<(usize, I)> = (0..measurements)
+            .map(|_| {
+                let class = rng.gen_range(0..2);
+                let input = if class == 0 {
+                    fixed()
+                } else {
+                    random(&mut rng)
+                };
+                (class, input)
+            })
+            .collect();
+
+        let mut timings = [Vec::new(), Vec::new()];
+        for (class, input) in &inputs {
+            let start = Instant::now();
+            for _ in 0..16 {
+                op(black_box(input));
+            }
+            timings[*class].push(start.elapsed().as_nanos() as f64);
+        }
+
+        let mut all: Vec<f64> = timings.concat();
+        all.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let threshold = all[all.len() * 9 / 10];
+        let [a, b] = timings.map(|t| {
+            t.into_iter()
+                .filter(|&v| v <= threshold)
+                .collect::<Vec<_>>()
+        });
+        welch_t(&a, &b).abs()
+    }
+
+    #[test]
+    #[ignore = "timing test; run locally with `cargo test --release -- --ignored`"]
+    fn test_dudect_ct_mul() {
+        let (q, m) = modulus();
+        let t = dudect(
+            200_000,
+            || (FixedUint::ZERO, FixedUint::ZERO),
+            |rng| (random_residue(rng, &q, &m), random_residue(rng, &q, &m)),
+            |(a, b)| {
+                black_box(m.ct_mul(a, b));
+            },
+        );
+        assert!(t < 4.5, "ct_mul leaks timing: |t| = {t}");
+    }
+
+    #[test]
+    #[ignore = "timing test; run locally with `cargo test --release -- --ignored`"]
+    fn test_dudect_range_check() {
+        let (_, m) = modulus();
+        let bound = U256::from_u64(1);
+        let t = dudect(
+            50_000,
+            || CtPolynomial::from_centered(&[0; 64], m),
+            |rng| {
+                let values: Vec<i64> = (0..64).map(|_| rng.gen_range(-1000..=1000)).collect();
+                CtPolynomial::from_centered(&values, m)
+            },
+            |p| {
+                black_box(p.check_range_centered(&bound));
+                black_box(p.centered_coefficients());
+            },
+        );
+        assert!(t < 4.5, "range check leaks timing: |t| = {t}");
+    }
+
+    #[test]
+    #[ignore = "timing test; run locally with `cargo test --release -- --ignored`"]
+    fn test_dudect_detects_variable_time_bigint() {
+        // Sanity check of the harness: BigInt multiplication by zero is measurably faster.
+        let t = dudect(
+            200_000,
+            || BigInt::from(0),
+            |rng| rng.gen_bigint(2048),
+            |a| {
+                black_box(a * a);
+            },
+        );
+        assert!(t > 4.5, "harness failed to detect a timing leak: |t| = {t}");
+    }
+}
@@ -0,0 +1,139 @@
+use bigint_poly::Polynomial;
+use bigint_poly::decomposition::*;
+use bigint_poly::errors::PolynomialError;
+use num_bigint::BigInt;
+
+mod common;
+use common::poly;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_width_for_bound() {
+        assert_eq!(bit_width_for_bound(&BigInt::from(0)), 1);
+        assert_eq!(bit_width_for_bound(&BigInt::from(1)), 2);
+        assert_eq!(bit_width_for_bound(&BigInt::from(4)), 4);
+        assert_eq!(bit_width_for_bound(&BigInt::from(3)), 3);
+    }
+
+    #[test]
+    fn test_bit_decomposition_roundtrip() {
+        let bound = BigInt::from(5);
+        let p = poly(&[-5, 0, 3, 5]);
+        let bits = decompose_bits(&p, &bound).unwrap();
+
+        // -5 + 5 = 0, 0 + 5 = 5 = 0b0101
+        assert_eq!(bits[0], vec![false; 4]);
+        assert_eq!(bits[1], vec![true, false, true, false]);
+        assert_eq!(recompose_bits(&bits, &bound), p);
+    }
+
+    #[test]
+    fn test_limb_decomposition_roundtrip() {
+        let bound: BigInt = BigInt::from(1) << 40;
+        let p = Polynomial::new(vec![
+            -&bound,
+            BigInt::from(-123_456_789),
+            BigInt::from(987_654_321),
+            bound.clone(),
+        ]);
+        let limbs = decompose_limbs(&p, &bound, 16).unwrap();
+        assert!(limbs.iter().all(|l| l.len() == 3));
+        assert!(limbs.iter().flatten().all(|l| l < &BigInt::from(1 << 16)));
+        assert_eq!(recompose_limbs(&limbs, &bound, 16), p);
+    }
+
+    #[test]
+    fn test_decomposition_out_of_range() {
+        let bound = BigInt::from(3);
+        match decompose_bits(&poly(&[1, -4]), &bound) {
+            Err(PolynomialError::RangeViolations { violations, .. }) => {
+                assert_eq!(violations[0].index, 1);
+                assert_eq!(violations[0].value, BigInt::from(-4));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+        assert!(matches!(
+            decompose_bits(&poly(&[1]), &BigInt::from(-1)),
+            Err(PolynomialError::RangeCheckError { .. })
+        ));
+        assert!(matches!(
+            decompose_limbs(&poly(&[1]), &bound, 0),
+            Err(PolynomialError::InvalidPolynomial { .. })
+        ));
+    }
+
+    #[test]
+    fn test_gadget_decomposition_unsigned() {
+        let modulus = BigInt::from(1_000_003);
+        let gadget = GadgetDecomposition::new(BigInt::from(16), 5, false).unwrap();
+        let p = poly(&[-1, 0, 999_999, 123_456, 2_000_010]);
+
+        let digits = gadget.decompose(&p, &modulus).unwrap();
+        assert_eq!(digits.len(), 5);
+        assert!(digits.iter().all(|d| {
+            d.coefficients()
+                .iter()
+                .all(|c| c >= &BigInt::from(0) && c < &BigInt::from(16))
+        }));
+        assert_eq!(
+            gadget.recompose(&digits, &modulus),
+            p.reduce_and_center(&modulus)
+        );
+    }
+
+    #[test]
+    fn test_gadget_decomposition_balanced() {
+        let modulus = BigInt::from(1_000_003);
+        let gadget = GadgetDecomposition::new(BigInt::from(16), 6, true).unwrap();
+        let p = poly(&[-1, 0, 499_999, -500_001, 123_456]);
+
+        let digits = gadget.decompose(&p, &modulus).unwrap();
+        assert!(digits.iter().all(|d| {
+            d.coefficients()
+                .iter()
+                .all(|c| c >= &BigInt::from(-8) && c < &BigInt::from(8))
+        }));
+        assert_eq!(
+            gadget.recompose(&digits, &modulus),
+            p.reduce_and_center(&modulus)
+        );
+    }
+
+    #[test]
+    fn test_gadget_decomposition_rns() {
+        let moduli = vec![BigInt::from(65537), BigInt::from(786433)];
+        let gadget = GadgetDecomposition::new(BigInt::from(256), 3, true).unwrap();
+        let p = poly(&[1_234_567, -7_654_321, 42]);
+
+        let digits = gadget.decompose_rns(&p, &moduli).unwrap();
+        let recomposed = gadget.recompose_rns(&digits, &moduli);
+        for (limb, modulus) in recomposed.iter().zip(&moduli) {
+            assert_eq!(limb, &p.reduce_and_center(modulus));
+        }
+    }
+
+    #[test]
+    fn test_gadget_decomposition_errors() {
+        assert!(matches!(
+            GadgetDecomposition::new(BigInt::from(1), 3, false),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+        assert!(matches!(
+            GadgetDecomposition::new(BigInt::from(2), 0, false),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+
+        let gadget = GadgetDecomposition::new(BigInt::from(4), 2, false).unwrap();
+        assert!(matches!(
+            gadget.decompose(&poly(&[20]), &BigInt::from(97)),
+            Err(PolynomialError::ArithmeticError { .. })
+        ));
+        assert_eq!(
+            gadget.gadget_vector(&BigInt::from(7)),
+            vec![BigInt::from(1), BigInt::from(-3)]
+        );
+    }
+}
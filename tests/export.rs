@@ -0,0 +1,108 @@
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::export::*;
+use num_bigint::BigInt;
+
+mod common;
+use common::poly;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_noir_prover_toml() {
+        let a = poly(&[1, -2, 3]);
+        let b = poly(&[4]);
+        let toml = export_witnesses(
+            &[("a", &a), ("b", &b)],
+            WitnessFormat::NoirProverToml,
+            &ExportOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(toml, "a = [\"1\", \"-2\", \"3\"]\nb = [\"4\"]\n");
+    }
+
+    #[test]
+    fn test_circom_input_with_options() {
+        let a = poly(&[1, -2, 3]);
+        let options = ExportOptions {
+            order: CoefficientOrder::Ascending,
+            field_modulus: Some(BigInt::from(17)),
+            pad_to: Some(4),
+        };
+        let json = export_witnesses(&[("a", &a)], WitnessFormat::CircomInput, &options).unwrap();
+        assert_eq!(json, "{\n  \"a\": [\"3\", \"15\", \"1\", \"0\"]\n}\n");
+    }
+
+    #[test]
+    fn test_generic_json() {
+        let a = poly(&[5, 6]);
+        let b = poly(&[7]);
+        let json = export_witnesses(
+            &[("a", &a), ("b", &b)],
+            WitnessFormat::Json,
+            &ExportOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            json,
+            "{\n  \"a\": {\n    \"order\": \"descending\",\n    \"coefficients\": [\"5\", \"6\"]\n  },\n  \"b\": {\n    \"order\": \"descending\",\n    \"coefficients\": [\"7\"]\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_write_witnesses() {
+        let a = poly(&[1, 2]);
+        let mut buffer = Vec::new();
+        write_witnesses(
+            &mut buffer,
+            &[("a", &a)],
+            WitnessFormat::NoirProverToml,
+            &ExportOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "a = [\"1\", \"2\"]\n");
+    }
+
+    #[test]
+    fn test_export_errors() {
+        let a = poly(&[1, 2, 3]);
+        let options = ExportOptions::default();
+        assert!(matches!(
+            export_witnesses(&[("1a", &a)], WitnessFormat::Json, &options),
+            Err(PolynomialError::ExportError { .. })
+        ));
+        assert!(matches!(
+            export_witnesses(&[("a", &a), ("a", &a)], WitnessFormat::Json, &options),
+            Err(PolynomialError::ExportError { .. })
+        ));
+
+        let padded = ExportOptions {
+            pad_to: Some(2),
+            ..ExportOptions::default()
+        };
+        assert!(matches!(
+            export_witnesses(&[("a", &a)], WitnessFormat::Json, &padded),
+            Err(PolynomialError::ExportError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_field_lifting_checks_range() {
+        let field = |p: i64| ExportOptions {
+            field_modulus: Some(BigInt::from(p)),
+            ..ExportOptions::default()
+        };
+        let json = export_witnesses(&[("a", &poly(&[-8, 8]))], WitnessFormat::Json, &field(17));
+        assert!(json.unwrap().contains("[\"9\", \"8\"]"));
+        let json = export_witnesses(&[("a", &poly(&[-8, 7]))], WitnessFormat::Json, &field(16));
+        assert!(json.unwrap().contains("[\"8\", \"7\"]"));
+
+        for (coeffs, p) in [(&[9, 0], 17), (&[0, -9], 17), (&[8, 0], 16), (&[0, -9], 16)] {
+            assert!(matches!(
+                export_witnesses(&[("a", &poly(coeffs))], WitnessFormat::Json, &field(p)),
+                Err(PolynomialError::ExportError { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use bigint_poly::Polynomial;
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::field::*;
+use num_bigint::BigInt;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predefined_moduli() {
+        let bn254 = FieldModulus::bn254();
+        assert_eq!(bn254.modulus().bits(), 254);
+        let bls = FieldModulus::bls12_381();
+        assert_eq!(bls.modulus().bits(), 255);
+    }
+
+    #[test]
+    fn test_to_and_from_field() {
+        let field = FieldModulus::bn254();
+        let minus_one = field.to_field(&BigInt::from(-1)).unwrap();
+        assert_eq!(minus_one, field.modulus() - 1);
+        assert_eq!(field.from_field(&minus_one).unwrap(), BigInt::from(-1));
+
+        assert!(matches!(
+            field.to_field(field.modulus()),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+        assert!(matches!(
+            field.from_field(field.modulus()),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let field = FieldModulus::bls12_381();
+        let poly = Polynomial::new(vec![BigInt::from(-3), BigInt::from(0), BigInt::from(258)]);
+
+        for endianness in [Endianness::Little, Endianness::Big] {
+            let encoded = field.encode_polynomial(&poly, endianness).unwrap();
+            assert_eq!(encoded.len(), 3);
+            assert_eq!(field.decode_polynomial(&encoded, endianness).unwrap(), poly);
+        }
+
+        let little = field.encode_polynomial(&poly, Endianness::Little).unwrap();
+        assert_eq!(little[2][..2], [2, 1]);
+        let big = field.encode_polynomial(&poly, Endianness::Big).unwrap();
+        assert_eq!(big[2][30..], [1, 2]);
+    }
+
+    #[test]
+    fn test_invalid_modulus() {
+        for modulus in [
+            BigInt::from(1),
+            BigInt::from(2),
+            BigInt::from(1) << 256,
+            BigInt::from(8),
+            FieldModulus::bn254().modulus() + 1,
+        ] {
+            assert!(matches!(
+                FieldModulus::new_unchecked(modulus),
+                Err(PolynomialError::ModulusError { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_unchecked_modulus_roundtrip() {
+        let field = FieldModulus::new_unchecked(BigInt::from(7)).unwrap();
+        let poly = Polynomial::new((-3..=3).map(BigInt::from).collect());
+        for endianness in [Endianness::Little, Endianness::Big] {
+            let encoded = field.encode_polynomial(&poly, endianness).unwrap();
+            assert_eq!(field.decode_polynomial(&encoded, endianness).unwrap(), poly);
+        }
+        assert!(field.to_field(&BigInt::from(4)).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::fixed::*;
+use num_bigint::{BigInt, BigUint, RandBigInt};
+use num_integer::Integer;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+mod common;
+use common::poly;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns `2^bits - c`.
+    fn modulus(bits: u32, c: u64) -> BigInt {
+        (BigInt::from(1) << bits) - c
+    }
+
+    fn check_arithmetic<const L: usize>(q: &BigInt) {
+        let m = FixedModulus::<L>::new(q).unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..200 {
+            let a = rng.gen_bigint_range(&BigInt::from(0), q);
+            let b = rng.gen_bigint_range(&BigInt::from(0), q);
+            let (fa, fb) = (m.reduce_bigint(&a), m.reduce_bigint(&b));
+            let to_big = |x: FixedUint<L>| BigInt::from(x.to_biguint());
+
+            assert_eq!(to_big(fa), a);
+            assert_eq!(to_big(m.add(&fa, &fb)), (&a + &b).mod_floor(q));
+            assert_eq!(to_big(m.sub(&fa, &fb)), (&a - &b).mod_floor(q));
+            assert_eq!(to_big(m.neg(&fa)), (-&a).mod_floor(q));
+            assert_eq!(to_big(m.mul(&fa, &fb)), (&a * &b).mod_floor(q));
+        }
+    }
+
+    #[test]
+    fn test_modular_arithmetic_matches_bigint() {
+        check_arithmetic::<2>(&modulus(127, 1));
+        check_arithmetic::<2>(&modulus(128, 159));
+        check_arithmetic::<4>(&modulus(255, 19));
+        check_arithmetic::<8>(&modulus(500, 1));
+        check_arithmetic::<8>(&BigInt::from(65537));
+    }
+
+    #[test]
+    fn test_fixed_uint_conversions() {
+        let x = BigUint::from(u64::MAX) * 3u32;
+        let fx = U128::from_biguint(&x).unwrap();
+
+        assert_eq!(fx.limbs(), &[u64::MAX - 2, 2]);
+        assert_eq!(fx.to_biguint(), x);
+        assert_eq!(fx.bits(), 66);
+        assert_eq!(fx.to_string(), x.to_string());
+        assert!(U128::from_biguint(&(BigUint::from(1u32) << 128)).is_none());
+        assert!(U128::from_u64(1) < U128::from_limbs([0, 1]));
+    }
+
+    #[test]
+    fn test_invalid_moduli() {
+        for q in [
+            BigInt::from(0),
+            BigInt::from(1),
+            BigInt::from(-7),
+            modulus(200, 2),
+            modulus(129, 1),
+        ] {
+            assert!(matches!(
+                FixedModulus::<2>::new(&q),
+                Err(PolynomialError::ModulusError { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_polynomial_matches_bigint() {
+        let q = modulus(255, 19);
+        let m = FixedModulus::<4>::new(&q).unwrap();
+        let a = poly(&[i64::MAX, -3, 0, 11, -(1 << 40)]).scalar_mul(&(BigInt::from(1) << 180));
+        let b = poly(&[-7, 2, i64::MIN]);
+        let (fa, fb) = (
+            FixedPolynomial::from_polynomial(&a, m),
+            FixedPolynomial::from_polynomial(&b, m),
+        );
+
+        assert_eq!(fa.to_polynomial(), a.reduce_and_center(&q));
+        assert_eq!(
+            fa.add(&fb).unwrap().to_polynomial(),
+            a.add(&b).reduce_and_center(&q)
+        );
+        assert_eq!(
+            fa.sub(&fb).unwrap().to_polynomial(),
+            a.sub(&b).reduce_and_center(&q)
+        );
+        assert_eq!(fa.neg().to_polynomial(), a.neg().reduce_and_center(&q));
+        assert_eq!(
+            fa.mul(&fb).unwrap().to_polynomial(),
+            a.mul(&b).reduce_and_center(&q)
+        );
+        let scalar = -modulus(300, 0);
+        assert_eq!(
+            fa.scalar_mul(&scalar).to_polynomial(),
+            a.scalar_mul(&scalar).reduce_and_center(&q)
+        );
+    }
+
+    #[test]
+    fn test_mismatched_moduli() {
+        let p = poly(&[1, 2]);
+        let a =
+            FixedPolynomial::from_polynomial(&p, FixedModulus::<2>::new(&modulus(127, 1)).unwrap());
+        let b = FixedPolynomial::from_polynomial(
+            &p,
+            FixedModulus::<2>::new(&modulus(128, 159)).unwrap(),
+        );
+        assert!(matches!(
+            a.mul(&b),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+    }
+}
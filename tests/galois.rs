@@ -0,0 +1,142 @@
+use bigint_poly::Polynomial;
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::galois::*;
+use num_bigint::BigInt;
+use num_integer::Integer;
+
+mod common;
+use common::ascending;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_automorphism_signed_permutation() {
+        // a = 1 + 2x + 3x^2 + 4x^3 in Z[x]/(x^4 + 1), σ_3: x -> x^3.
+        // x^2 -> x^6 = -x^2, x^3 -> x^9 = x.
+        let a = ascending(&[1, 2, 3, 4]);
+        assert_eq!(a.automorphism(3, 4).unwrap(), ascending(&[1, 4, -3, 2]));
+
+        // σ_1 is the identity, and k is taken modulo 2n.
+        assert_eq!(a.automorphism(1, 4).unwrap(), a);
+        assert_eq!(
+            a.automorphism(11, 4).unwrap(),
+            a.automorphism(3, 4).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_automorphism_composition_and_reduction() {
+        let a = ascending(&[5, -1, 0, 7, 2, 3, -4, 9]);
+        let composed = a.automorphism(5, 8).unwrap().automorphism(3, 8).unwrap();
+        assert_eq!(composed, a.automorphism(15, 8).unwrap());
+
+        // Coefficients beyond x^{n-1} are folded first: x^4 = -1 modulo x^4 + 1.
+        let long = ascending(&[1, 0, 0, 0, 2]);
+        assert_eq!(long.automorphism(3, 4).unwrap(), ascending(&[-1, 0, 0, 0]));
+    }
+
+    #[test]
+    fn test_automorphism_errors() {
+        let a = ascending(&[1, 2]);
+        assert!(matches!(
+            a.automorphism(2, 4),
+            Err(PolynomialError::CyclotomicError { .. })
+        ));
+        assert!(matches!(
+            a.automorphism(3, 6),
+            Err(PolynomialError::CyclotomicError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_galois_elements() {
+        assert_eq!(rotation_galois_element(1, 8).unwrap(), 5);
+        assert_eq!(rotation_galois_element(2, 8).unwrap(), 9);
+        assert_eq!(rotation_galois_element(4, 8).unwrap(), 1);
+        assert_eq!(rotation_galois_element(-1, 8).unwrap(), 13);
+        assert_eq!(conjugation_galois_element(8).unwrap(), 15);
+        assert!(conjugation_galois_element(12).is_err());
+    }
+
+    #[test]
+    fn test_dimension_one_matches_negacyclic_helpers() {
+        // Z[x]/(x + 1) has the trivial Galois group, as for `Polynomial::automorphism`.
+        assert_eq!(rotation_galois_element(3, 1).unwrap(), 1);
+        assert_eq!(conjugation_galois_element(1).unwrap(), 1);
+        assert_eq!(automorphism_ntt_permutation(1, 1).unwrap(), vec![0]);
+        assert_eq!(
+            ascending(&[1, 2]).automorphism(1, 1).unwrap(),
+            ascending(&[-1])
+        );
+        assert!(matches!(
+            rotation_galois_element(1, 0),
+            Err(PolynomialError::CyclotomicError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_automorphism_ntt_permutation() {
+        let n = 8;
+        let p = BigInt::from(97);
+        // Find a primitive 16th root of unity modulo 97, i.e. zeta^8 = -1.
+        let zeta = (2..97)
+            .map(BigInt::from)
+            .find(|z| z.modpow(&BigInt::from(n), &p) == &p - 1)
+            .unwrap();
+        let ntt = |poly: &Polynomial| -> Vec<BigInt> {
+            (0..n)
+                .map(|j| {
+                    let point = zeta.modpow(&BigInt::from(2 * j + 1), &p);
+                    poly.evaluate(&point).mod_floor(&p)
+                })
+                .collect()
+        };
+
+        let a = ascending(&[3, 1, 4, 1, 5, 9, 2, 6]);
+        for k in [3, 5, 15] {
+            let perm = automorphism_ntt_permutation(k, n as usize).unwrap();
+            let values = ntt(&a);
+            let permuted: Vec<BigInt> = perm.iter().map(|&i| values[i].clone()).collect();
+            assert_eq!(permuted, ntt(&a.automorphism(k, n as usize).unwrap()));
+        }
+    }
+
+    fn direct_trace(a: &Polynomial, n: usize, k: u32) -> Polynomial {
+        let step = (2 * n) >> k;
+        (0..(1usize << k))
+            .map(|m| a.automorphism(1 + m * step, n).unwrap())
+            .fold(Polynomial::new(vec![]), |acc, p| acc.add(&p))
+    }
+
+    #[test]
+    fn test_trace_matches_direct_summation() {
+        let n = 16;
+        let a = Polynomial::from_ascending_coefficients((1..=16).map(BigInt::from).collect());
+        for k in 0..=4 {
+            assert_eq!(a.trace(n, k).unwrap(), direct_trace(&a, n, k));
+        }
+
+        // Tr to dimension n / 4 keeps the coefficients of x^{4m}, scaled by 4.
+        let expected: Vec<i64> = (1..=16)
+            .map(|i| if (i - 1) % 4 == 0 { 4 * i } else { 0 })
+            .collect();
+        assert_eq!(a.trace(n, 2).unwrap(), ascending(&expected));
+    }
+
+    #[test]
+    fn test_full_trace() {
+        let n = 8;
+        let a = ascending(&[3, 1, 4, 1, 5, 9, 2, 6]);
+        assert_eq!(
+            a.full_trace(n).unwrap(),
+            ascending(&[24, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(a.full_trace(n).unwrap(), direct_trace(&a, n, 3));
+        assert!(matches!(
+            a.trace(n, 4),
+            Err(PolynomialError::CyclotomicError { .. })
+        ));
+    }
+}
@@ -0,0 +1,196 @@
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::{MonomialOrder, MultivariatePolynomial, Polynomial};
+use num_bigint::BigInt;
+use std::cmp::Ordering;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mpoly(order: MonomialOrder, terms: &[(&[usize], i64)]) -> MultivariatePolynomial {
+        let terms: Vec<_> = terms
+            .iter()
+            .map(|(e, c)| (e.to_vec(), BigInt::from(*c)))
+            .collect();
+        let num_vars = terms.first().map_or(2, |(e, _)| e.len());
+        MultivariatePolynomial::new(num_vars, order, terms).unwrap()
+    }
+
+    fn exponents(p: &MultivariatePolynomial) -> Vec<Vec<usize>> {
+        p.terms().iter().map(|(e, _)| e.clone()).collect()
+    }
+
+    #[test]
+    fn test_monomial_orders() {
+        let lex = MonomialOrder::Lex;
+        let grevlex = MonomialOrder::GrevLex;
+
+        assert_eq!(lex.compare(&[1, 0, 0], &[0, 3, 3]), Ordering::Greater);
+        assert_eq!(grevlex.compare(&[1, 0, 0], &[0, 3, 3]), Ordering::Less);
+        // Same total degree: x0*x2 < x1^2 in grevlex, but x0*x2 > x1^2 in lex.
+        assert_eq!(grevlex.compare(&[1, 0, 1], &[0, 2, 0]), Ordering::Less);
+        assert_eq!(lex.compare(&[1, 0, 1], &[0, 2, 0]), Ordering::Greater);
+        assert_eq!(grevlex.compare(&[1, 1, 0], &[1, 1, 0]), Ordering::Equal);
+
+        let terms: &[(&[usize], i64)] = &[
+            (&[0, 0, 0], 1),
+            (&[0, 2, 0], 1),
+            (&[1, 0, 1], 1),
+            (&[1, 0, 0], 1),
+        ];
+        assert_eq!(
+            exponents(&mpoly(lex, terms)),
+            vec![vec![1, 0, 1], vec![1, 0, 0], vec![0, 2, 0], vec![0, 0, 0]]
+        );
+        let p = mpoly(grevlex, terms);
+        assert_eq!(
+            exponents(&p),
+            vec![vec![0, 2, 0], vec![1, 0, 1], vec![1, 0, 0], vec![0, 0, 0]]
+        );
+        assert_eq!(p.leading_term().unwrap().0, vec![0, 2, 0]);
+        assert_eq!(p.with_order(lex), mpoly(lex, terms));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let order = MonomialOrder::Lex;
+        let x = MultivariatePolynomial::variable(0, 2, order).unwrap();
+        let y = MultivariatePolynomial::variable(1, 2, order).unwrap();
+        let one = MultivariatePolynomial::constant(2, order, BigInt::from(1));
+
+        // (x + y)(x - y) = x^2 - y^2
+        let product = x.add(&y).unwrap().mul(&x.sub(&y).unwrap()).unwrap();
+        assert_eq!(product, mpoly(order, &[(&[2, 0], 1), (&[0, 2], -1)]));
+        assert_eq!(product.to_string(), "x0^2 - x1^2");
+        assert_eq!(product.total_degree(), 2);
+        assert_eq!(product.degree_in(1), 2);
+
+        let q = product
+            .add(&one.scalar_mul(&BigInt::from(-3)))
+            .unwrap()
+            .mul(&x)
+            .unwrap();
+        assert_eq!(q.to_string(), "x0^3 - x0*x1^2 - 3*x0");
+        assert!(q.sub(&q).unwrap().is_zero());
+        assert_eq!(
+            q.neg().add(&q).unwrap(),
+            MultivariatePolynomial::zero(2, order)
+        );
+        assert!(x.scalar_mul(&BigInt::from(0)).is_zero());
+
+        let z = MultivariatePolynomial::variable(0, 3, order).unwrap();
+        assert!(matches!(
+            x.mul(&z),
+            Err(PolynomialError::DimensionError { .. })
+        ));
+        assert!(matches!(
+            MultivariatePolynomial::variable(2, 2, order),
+            Err(PolynomialError::DimensionError { .. })
+        ));
+        assert!(matches!(
+            MultivariatePolynomial::new(2, order, vec![(vec![1], BigInt::from(1))]),
+            Err(PolynomialError::DimensionError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_evaluation_and_partial_evaluation() {
+        // f = 3*x0^2*x1 - x1^3 + 5
+        let f = mpoly(
+            MonomialOrder::GrevLex,
+            &[(&[2, 1], 3), (&[0, 3], -1), (&[0, 0], 5)],
+        );
+        let point = [BigInt::from(2), BigInt::from(-3)];
+        assert_eq!(
+            f.evaluate(&point).unwrap(),
+            BigInt::from(3 * 4 * -3 + 27 + 5)
+        );
+        assert!(matches!(
+            f.evaluate(&point[..1]),
+            Err(PolynomialError::DimensionError { .. })
+        ));
+
+        // f(2, x1) = -x1^3 + 12*x1 + 5
+        let g = f.partial_evaluate(0, &BigInt::from(2)).unwrap();
+        assert_eq!(g.num_vars(), 2);
+        assert_eq!(g.degree_in(0), 0);
+        assert_eq!(
+            g.evaluate(&[BigInt::from(100), BigInt::from(-3)]).unwrap(),
+            f.evaluate(&point).unwrap()
+        );
+        assert_eq!(
+            g.to_polynomial().unwrap(),
+            Polynomial::new(vec![-1, 0, 12, 5].into_iter().map(BigInt::from).collect())
+        );
+        assert_eq!(
+            g.partial_evaluate(1, &BigInt::from(-3)).unwrap(),
+            MultivariatePolynomial::constant(
+                2,
+                MonomialOrder::GrevLex,
+                f.evaluate(&point).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_univariate_conversion() {
+        let p = Polynomial::new(vec![4, 0, -2, 7].into_iter().map(BigInt::from).collect());
+        let m = MultivariatePolynomial::from_polynomial(&p, 1, 3, MonomialOrder::Lex).unwrap();
+
+        assert_eq!(m.degree_in(1), 3);
+        assert_eq!(m.to_polynomial().unwrap(), p);
+        for x in -3..4 {
+            let x = BigInt::from(x);
+            let point = [BigInt::from(9), x.clone(), BigInt::from(-9)];
+            assert_eq!(m.evaluate(&point).unwrap(), p.evaluate(&x));
+        }
+
+        let constant = MultivariatePolynomial::constant(3, MonomialOrder::Lex, BigInt::from(6));
+        assert_eq!(
+            constant.to_polynomial().unwrap(),
+            Polynomial::new(vec![BigInt::from(6)])
+        );
+        let mixed = m
+            .add(&MultivariatePolynomial::variable(0, 3, MonomialOrder::Lex).unwrap())
+            .unwrap();
+        assert!(matches!(
+            mixed.to_polynomial(),
+            Err(PolynomialError::InvalidPolynomial { .. })
+        ));
+        assert!(matches!(
+            MultivariatePolynomial::from_polynomial(&p, 3, 3, MonomialOrder::Lex),
+            Err(PolynomialError::DimensionError { .. })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialization_checks_and_normalizes_terms() {
+        let p = mpoly(MonomialOrder::GrevLex, &[(&[1, 2], 3), (&[0, 0], -1)]);
+        let bytes = bincode::serialize(&p).unwrap();
+        assert_eq!(
+            bincode::deserialize::<MultivariatePolynomial>(&bytes).unwrap(),
+            p
+        );
+
+        // Unsorted terms, a repeated monomial and a zero coefficient.
+        let terms: Vec<(Vec<usize>, BigInt)> = [
+            (vec![0, 1], 2),
+            (vec![2, 0], 1),
+            (vec![0, 1], -2),
+            (vec![1, 1], 0),
+            (vec![0, 0], 5),
+        ]
+        .into_iter()
+        .map(|(e, c)| (e, BigInt::from(c)))
+        .collect();
+        let bytes = bincode::serialize(&(2usize, MonomialOrder::Lex, terms)).unwrap();
+        let q: MultivariatePolynomial = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(q, mpoly(MonomialOrder::Lex, &[(&[2, 0], 1), (&[0, 0], 5)]));
+
+        // An exponent vector with the wrong number of variables.
+        let terms = vec![(vec![1, 0, 0], BigInt::from(1))];
+        let bytes = bincode::serialize(&(2usize, MonomialOrder::Lex, terms)).unwrap();
+        assert!(bincode::deserialize::<MultivariatePolynomial>(&bytes).is_err());
+    }
+}
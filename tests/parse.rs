@@ -0,0 +1,120 @@
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::{GenericPolynomial, MAX_PARSED_DEGREE, Polynomial};
+use num_bigint::BigInt;
+
+mod common;
+use common::poly;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Polynomial {
+        s.parse().unwrap()
+    }
+
+    fn syntax_error(s: &str) -> (usize, String) {
+        match s.parse::<Polynomial>() {
+            Err(PolynomialError::SyntaxError { position, message }) => (position, message),
+            other => panic!("expected a syntax error for {s:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        for p in [
+            poly(&[3, 0, -1, 5]),
+            poly(&[-3, 1]),
+            poly(&[1, 0, 0, 0, 1]),
+            poly(&[-1, -1, -1]),
+            poly(&[7]),
+            poly(&[-7]),
+            poly(&[0]),
+            Polynomial::new(vec![(BigInt::from(1) << 300u32) - 1, BigInt::from(-2)]),
+        ] {
+            assert_eq!(parse(&p.to_string()), p, "roundtrip of {p}");
+        }
+        assert_eq!(poly(&[-3, 0, 1]).to_string(), "-3x^2 + 1");
+        assert_eq!(parse("3x^2 - x + 5"), poly(&[3, -1, 5]));
+    }
+
+    #[test]
+    fn test_variants() {
+        let expected = poly(&[3, -1, 5]);
+        for s in [
+            "3*x^2 - 1*x + 5",
+            "3*x**2 - x**1 + 5*x^0",
+            "3x^2-x+5",
+            "  3 x ^ 2  -  x  +  5  ",
+            "5 - x + 3x^2",
+            "x^2 + 2x^2 + 5 - x",
+            "0x3 x^2 - 0X1*x + 0x5",
+            "3x^2 + -x + 5",
+            "- -3x^2 - x + 5",
+            "3x^2 - x + 0x^7 + 5",
+        ] {
+            assert_eq!(parse(s), expected, "parsing {s:?}");
+        }
+
+        assert_eq!(parse("0xff"), poly(&[255]));
+        assert_eq!(parse("0xffx^2"), poly(&[255, 0, 0]));
+        assert_eq!(parse("x - x"), poly(&[0]));
+        assert_eq!(parse("-x^3"), poly(&[-1, 0, 0, 0]));
+        assert_eq!(
+            parse("-0xdeadbeefdeadbeefdeadbeef x"),
+            Polynomial::new(vec![
+                -BigInt::parse_bytes(b"deadbeefdeadbeefdeadbeef", 16).unwrap(),
+                BigInt::from(0)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_error_positions() {
+        assert_eq!(syntax_error("").0, 0);
+        assert_eq!(syntax_error("   ").0, 3);
+        assert_eq!(
+            syntax_error("3x^2 + y"),
+            (7, "unexpected character 'y'".to_string())
+        );
+        assert_eq!(syntax_error("3x^2 x").0, 5);
+        assert_eq!(
+            syntax_error("3x^ + 1"),
+            (4, "expected an exponent".to_string())
+        );
+        assert_eq!(syntax_error("3 * 4").0, 4);
+        assert_eq!(syntax_error("x + ").1, "unexpected end of input");
+        assert_eq!(syntax_error("2x + é").0, 5);
+        assert_eq!(
+            syntax_error("x^99999999999999999999999").1,
+            "exponent is too large"
+        );
+        // Exponents that fit in usize but would need a huge dense allocation.
+        for s in [
+            "x^100000000000".to_string(),
+            "x^9223372036854775806".to_string(),
+            format!("x^{}", MAX_PARSED_DEGREE + 1),
+        ] {
+            assert_eq!(syntax_error(&s), (2, "exponent is too large".to_string()));
+        }
+        assert_eq!(syntax_error("1 + 3x ** 100000000000").0, 10);
+
+        let err = "1 +* x".parse::<Polynomial>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Syntax error at position 3: unexpected character '*'"
+        );
+    }
+
+    #[test]
+    fn test_generic_coefficients() {
+        let p: GenericPolynomial<i64> = "2x^2 - 7".parse().unwrap();
+        assert_eq!(p.coefficients(), &[2, 0, -7]);
+
+        let too_large = format!("{}x + 1", u128::MAX);
+        assert!(matches!(
+            too_large.parse::<GenericPolynomial<i64>>(),
+            Err(PolynomialError::ArithmeticError { .. })
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use bigint_poly::{Polynomial, errors::PolynomialError};
+use bigint_poly::{Polynomial, RingReduction, RoundingMode, errors::PolynomialError};
 use num_bigint::BigInt;
 
 #[cfg(test)]
@@ -164,6 +164,234 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_reduce_in_ring_with_quotients() {
+        // x^4 + 1
+        let cyclo = vec![
+            BigInt::from(1),
+            BigInt::from(0),
+            BigInt::from(0),
+            BigInt::from(0),
+            BigInt::from(1),
+        ];
+        let modulus = BigInt::from(17);
+        let a = Polynomial::new(vec![
+            BigInt::from(9),
+            BigInt::from(-4),
+            BigInt::from(12),
+            BigInt::from(3),
+        ]);
+        let b = Polynomial::new(vec![
+            BigInt::from(15),
+            BigInt::from(2),
+            BigInt::from(-8),
+            BigInt::from(1),
+        ]);
+        let product = a.mul(&b);
+
+        let reduction = product
+            .reduce_in_ring_with_quotients(&cyclo, &modulus)
+            .unwrap();
+
+        let mut expected = product.coefficients().to_vec();
+        bigint_poly::utils::reduce_in_ring(&mut expected, &cyclo, &modulus);
+        assert_eq!(reduction.result.coefficients(), expected.as_slice());
+        assert!(reduction.verify(&product, &cyclo, &modulus));
+
+        let tampered = RingReduction {
+            r1: reduction.r1.add(&Polynomial::constant(BigInt::from(1))),
+            ..reduction
+        };
+        assert!(!tampered.verify(&product, &cyclo, &modulus));
+    }
+
+    #[test]
+    fn test_reduce_in_ring_with_quotients_non_monic() {
+        let cyclo = vec![BigInt::from(2), BigInt::from(0), BigInt::from(1)];
+        let poly = Polynomial::new(vec![BigInt::from(1), BigInt::from(2), BigInt::from(3)]);
+        assert!(matches!(
+            poly.reduce_in_ring_with_quotients(&cyclo, &BigInt::from(7)),
+            Err(PolynomialError::CyclotomicError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_coefficient_norms() {
+        let poly = Polynomial::new(vec![BigInt::from(3), BigInt::from(-4), BigInt::from(15)]);
+        assert_eq!(poly.infinity_norm(None), BigInt::from(15));
+        assert_eq!(poly.l1_norm(None), BigInt::from(22));
+        assert_eq!(poly.l2_norm_squared(None), BigInt::from(250));
+
+        // Centered modulo 17, the coefficients are [3, -4, -2].
+        let modulus = BigInt::from(17);
+        assert_eq!(poly.infinity_norm(Some(&modulus)), BigInt::from(4));
+        assert_eq!(poly.l1_norm(Some(&modulus)), BigInt::from(9));
+        assert_eq!(poly.l2_norm_squared(Some(&modulus)), BigInt::from(29));
+
+        assert_eq!(Polynomial::new(vec![]).infinity_norm(None), BigInt::from(0));
+    }
+
+    #[test]
+    fn test_canonical_embedding_norm() {
+        // A constant embeds as itself at every root of unity.
+        let constant = Polynomial::constant(BigInt::from(-5));
+        let norm = constant.canonical_embedding_norm(8, None).unwrap();
+        assert!((norm - 5.0).abs() < 1e-9);
+
+        // A monomial has absolute value 1 at every root of unity, even after reduction.
+        let mut coeffs = vec![BigInt::from(0); 11];
+        coeffs[0] = BigInt::from(1);
+        let monomial = Polynomial::new(coeffs);
+        let norm = monomial.canonical_embedding_norm(4, None).unwrap();
+        assert!((norm - 1.0).abs() < 1e-9);
+
+        // 1 + x over x^2 + 1 evaluates to 1 ± i, with absolute value sqrt(2).
+        let poly = Polynomial::new(vec![BigInt::from(1), BigInt::from(1)]);
+        let norm = poly.canonical_embedding_norm(2, None).unwrap();
+        assert!((norm - 2f64.sqrt()).abs() < 1e-9);
+
+        assert!(matches!(
+            poly.canonical_embedding_norm(3, None),
+            Err(PolynomialError::CyclotomicError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_scale_and_round() {
+        let poly = Polynomial::new(vec![BigInt::from(-15), BigInt::from(15), BigInt::from(-16)]);
+        let t = BigInt::from(1);
+        let q = BigInt::from(10);
+
+        let away = poly
+            .scale_and_round(&t, &q, RoundingMode::NearestTiesAway)
+            .unwrap();
+        assert_eq!(
+            away.coefficients(),
+            &[BigInt::from(-2), BigInt::from(2), BigInt::from(-2)]
+        );
+        let floor = poly.scale_and_round(&t, &q, RoundingMode::Floor).unwrap();
+        assert_eq!(
+            floor.coefficients(),
+            &[BigInt::from(-2), BigInt::from(1), BigInt::from(-2)]
+        );
+        let ceil = poly.scale_and_round(&t, &q, RoundingMode::Ceil).unwrap();
+        assert_eq!(
+            ceil.coefficients(),
+            &[BigInt::from(-1), BigInt::from(2), BigInt::from(-1)]
+        );
+        assert!(matches!(
+            poly.scale_and_round(&t, &BigInt::from(0), RoundingMode::Floor),
+            Err(PolynomialError::DivisionByZero)
+        ));
+    }
+
+    #[test]
+    fn test_switch_modulus() {
+        let poly = Polynomial::new(vec![
+            BigInt::from(15),
+            BigInt::from(-15),
+            BigInt::from(14),
+            BigInt::from(1234),
+        ]);
+        let switched = poly
+            .switch_modulus(&BigInt::from(1001), &BigInt::from(101))
+            .unwrap();
+        // 1234 is centered to 233 modulo 1001; 233 * 101 / 1001 = 23.5...
+        assert_eq!(
+            switched.coefficients(),
+            &[
+                BigInt::from(2),
+                BigInt::from(-2),
+                BigInt::from(1),
+                BigInt::from(24)
+            ]
+        );
+        assert!(matches!(
+            poly.switch_modulus(&BigInt::from(0), &BigInt::from(101)),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_bgv_switch_modulus() {
+        let t = BigInt::from(3);
+        let poly = Polynomial::new(vec![BigInt::from(307), BigInt::from(-307), BigInt::from(2)]);
+        let switched = poly
+            .bgv_switch_modulus(&BigInt::from(1000), &BigInt::from(100), &t)
+            .unwrap();
+        assert_eq!(
+            switched.coefficients(),
+            &[BigInt::from(31), BigInt::from(-31), BigInt::from(-1)]
+        );
+        for (a, b) in poly.coefficients().iter().zip(switched.coefficients()) {
+            assert_eq!(
+                bigint_poly::reduce_scalar(&(a % &t), &t),
+                bigint_poly::reduce_scalar(&(b % &t), &t)
+            );
+        }
+    }
+
+    #[test]
+    fn test_divide_and_round() {
+        let poly = Polynomial::new(vec![BigInt::from(7), BigInt::from(-8), BigInt::from(12)]);
+        let rescaled = poly
+            .divide_and_round(&BigInt::from(35), &BigInt::from(5))
+            .unwrap();
+        // Centered modulo 35: [7, -8, 12] -> [1.4, -1.6, 2.4] -> [1, -2, 2] modulo 7.
+        assert_eq!(
+            rescaled.coefficients(),
+            &[BigInt::from(1), BigInt::from(-2), BigInt::from(2)]
+        );
+        assert!(matches!(
+            poly.divide_and_round(&BigInt::from(35), &BigInt::from(3)),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_mul_by_monomial() {
+        let n = 4;
+        let mut cyclo = vec![BigInt::from(0); n + 1];
+        cyclo[0] = BigInt::from(1);
+        cyclo[n] = BigInt::from(1);
+        let poly = Polynomial::new(vec![
+            BigInt::from(4),
+            BigInt::from(-3),
+            BigInt::from(2),
+            BigInt::from(1),
+        ]);
+
+        for k in 0..(2 * n as i64) {
+            let mut monomial = vec![BigInt::from(0); k as usize + 1];
+            monomial[0] = BigInt::from(1);
+            let expected = poly
+                .mul(&Polynomial::new(monomial))
+                .reduce_by_cyclotomic(&cyclo)
+                .unwrap();
+            assert_eq!(poly.mul_by_monomial(k, n).unwrap(), expected);
+
+            // x^{2n} = 1, so shifting by k ± 2n gives the same result.
+            assert_eq!(poly.mul_by_monomial(k + 2 * n as i64, n).unwrap(), expected);
+            assert_eq!(poly.mul_by_monomial(k - 2 * n as i64, n).unwrap(), expected);
+        }
+
+        // x^{-1} * x = 1
+        let x = Polynomial::new(vec![BigInt::from(1), BigInt::from(0)]);
+        assert_eq!(
+            x.mul_by_monomial(-1, n).unwrap().coefficients(),
+            &[
+                BigInt::from(0),
+                BigInt::from(0),
+                BigInt::from(0),
+                BigInt::from(1)
+            ]
+        );
+        assert!(matches!(
+            poly.mul_by_monomial(1, 3),
+            Err(PolynomialError::CyclotomicError { .. })
+        ));
+    }
+
     #[cfg(feature = "serde")]
     mod serialization_tests {
         use super::*;
@@ -0,0 +1,189 @@
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::utils::reduce_coefficients_2d;
+use bigint_poly::{PolyMatrix, PolyVec, Polynomial};
+use num_bigint::BigInt;
+
+mod common;
+use common::ascending;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// x^4 + 1 in descending order.
+    fn cyclo() -> Vec<BigInt> {
+        [1, 0, 0, 0, 1].iter().map(|&v| BigInt::from(v)).collect()
+    }
+
+    fn ring_mul(a: &Polynomial, b: &Polynomial, q: &BigInt) -> Polynomial {
+        a.mul(b)
+            .reduce_by_cyclotomic(&cyclo())
+            .unwrap()
+            .reduce_and_center(q)
+    }
+
+    fn matrix() -> PolyMatrix {
+        PolyMatrix::new(vec![
+            vec![ascending(&[1, 2, 3, 4]), ascending(&[0, 1])],
+            vec![ascending(&[5, 0, 0, -1]), ascending(&[7])],
+            vec![ascending(&[0, 0, 9]), ascending(&[-3, 3, -3, 3])],
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_inner_product_reduces_in_ring() {
+        let q = BigInt::from(17);
+        let a = PolyVec::new(vec![ascending(&[1, 2, 3, 4]), ascending(&[0, 0, 0, 5])]);
+        let b = PolyVec::new(vec![ascending(&[0, 1]), ascending(&[0, 0, 1])]);
+
+        let expected = ring_mul(&a.polynomials()[0], &b.polynomials()[0], &q)
+            .add(&ring_mul(&a.polynomials()[1], &b.polynomials()[1], &q))
+            .reduce_and_center(&q);
+        assert_eq!(a.inner_product(&b, &cyclo(), &q).unwrap(), expected);
+        // x * (1 + 2x + 3x^2 + 4x^3) + x^2 * 5x^3 = -4 + x + 2x^2 + 3x^3 - 5x
+        assert_eq!(expected, ascending(&[-4, -4, 2, 3]));
+        assert_eq!(
+            a.dot(&b).unwrap().reduce_by_cyclotomic(&cyclo()).unwrap(),
+            ascending(&[-4, -4, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_matrix_vector_product() {
+        let q = BigInt::from(97);
+        let a = matrix();
+        let v = PolyVec::new(vec![ascending(&[1, -1]), ascending(&[0, 0, 0, 2])]);
+        let av = a.mul_vec(&v, &cyclo(), &q).unwrap();
+
+        assert_eq!(av.len(), 3);
+        for i in 0..3 {
+            let expected = ring_mul(a.get(i, 0), &v.polynomials()[0], &q)
+                .add(&ring_mul(a.get(i, 1), &v.polynomials()[1], &q))
+                .reduce_and_center(&q);
+            assert_eq!(av.polynomials()[i], expected);
+        }
+
+        assert!(matches!(
+            a.transpose().mul_vec(&v, &cyclo(), &q),
+            Err(PolynomialError::DimensionError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_transpose_and_matrix_product() {
+        let q = BigInt::from(97);
+        let a = matrix();
+        let at = a.transpose();
+
+        assert_eq!((at.rows(), at.cols()), (2, 3));
+        assert_eq!(at.get(1, 2), a.get(2, 1));
+        assert_eq!(at.transpose(), a);
+
+        // (A^T A)^T = A^T A, and (A^T A) v = A^T (A v).
+        let ata = at.mul(&a, &cyclo(), &q).unwrap();
+        assert_eq!((ata.rows(), ata.cols()), (2, 2));
+        assert_eq!(ata.transpose(), ata);
+
+        let v = PolyVec::new(vec![ascending(&[3]), ascending(&[0, 1])]);
+        let av = a.mul_vec(&v, &cyclo(), &q).unwrap();
+        assert_eq!(
+            ata.mul_vec(&v, &cyclo(), &q).unwrap(),
+            at.mul_vec(&av, &cyclo(), &q).unwrap()
+        );
+        assert!(matches!(
+            a.mul(&a, &cyclo(), &q),
+            Err(PolynomialError::DimensionError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_reductions_mirror_utils() {
+        let p = BigInt::from(7);
+        let v = PolyVec::new(vec![ascending(&[8, -3, 20]), ascending(&[-6, 13])]);
+
+        assert_eq!(
+            v.reduce_coefficients(&p).to_coefficients_2d(),
+            reduce_coefficients_2d(&v.to_coefficients_2d(), &p)
+        );
+        assert_eq!(
+            v.reduce_and_center(&p),
+            PolyVec::new(vec![ascending(&[1, -3, -1]), ascending(&[1, -1])])
+        );
+        assert_eq!(
+            v.reduce_in_ring(&cyclo(), &p).unwrap().polynomials()[0],
+            ascending(&[1, -3, -1])
+                .reduce_by_cyclotomic(&cyclo())
+                .unwrap()
+        );
+
+        let m = matrix();
+        assert_eq!(m.reduce_and_center(&p).get(1, 1), &ascending(&[0]));
+        assert_eq!(
+            m.reduce_coefficients(&p).get(2, 1),
+            &ascending(&[4, 3, 4, 3])
+        );
+        assert_eq!(
+            m.reduce_in_ring(&cyclo(), &p).unwrap().get(0, 0).degree(),
+            3
+        );
+
+        // An invalid cyclotomic polynomial is an error rather than a panic.
+        assert!(matches!(
+            v.reduce_in_ring(&[], &p),
+            Err(PolynomialError::DivisionByZero)
+        ));
+        assert!(
+            m.mul_vec(&v, &[BigInt::from(0), BigInt::from(1)], &p)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_vector_arithmetic_and_shapes() {
+        let a = PolyVec::new(vec![ascending(&[1, 2]), ascending(&[3])]);
+        let b = PolyVec::new(vec![ascending(&[0, 1]), ascending(&[-3])]);
+
+        assert_eq!(
+            a.add(&b).unwrap(),
+            PolyVec::new(vec![ascending(&[1, 3]), ascending(&[0])])
+        );
+        assert_eq!(a.sub(&b).unwrap().add(&b).unwrap(), a);
+        assert_eq!(
+            a.neg(),
+            PolyVec::new(vec![ascending(&[-1, -2]), ascending(&[-3])])
+        );
+        assert_eq!(
+            a.scalar_mul(&ascending(&[0, 1])),
+            PolyVec::new(vec![ascending(&[0, 1, 2]), ascending(&[0, 3])])
+        );
+        assert!(matches!(
+            a.add(&PolyVec::zero(3, 0)),
+            Err(PolynomialError::DimensionError { .. })
+        ));
+        assert!(matches!(
+            PolyMatrix::new(vec![vec![ascending(&[1])], vec![]]),
+            Err(PolynomialError::DimensionError { .. })
+        ));
+        assert_eq!(
+            PolyMatrix::zero(2, 3, 1)
+                .add(&PolyMatrix::zero(2, 3, 1))
+                .unwrap()
+                .row(1)
+                .len(),
+            3
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_matrix_deserialization_checks_shape() {
+        let m = matrix();
+        let mut bytes = bincode::serialize(&m).unwrap();
+        assert_eq!(bincode::deserialize::<PolyMatrix>(&bytes).unwrap(), m);
+
+        // bincode writes `rows` first, as a little-endian u64: claim 4 rows for 6 entries.
+        bytes[0] = 4;
+        assert!(bincode::deserialize::<PolyMatrix>(&bytes).is_err());
+    }
+}
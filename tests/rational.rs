@@ -0,0 +1,156 @@
+use bigint_poly::RationalPolynomial;
+use bigint_poly::errors::PolynomialError;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+
+mod common;
+use common::poly;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn q(n: i64, d: i64) -> BigRational {
+        BigRational::new(BigInt::from(n), BigInt::from(d))
+    }
+
+    fn rpoly(values: &[(i64, i64)]) -> RationalPolynomial {
+        RationalPolynomial::new(values.iter().map(|&(n, d)| q(n, d)).collect())
+    }
+
+    #[test]
+    fn test_normalization_and_display() {
+        let p = rpoly(&[(0, 1), (0, 1), (1, 2), (-1, 1), (3, 1)]);
+        assert_eq!(p.degree(), 2);
+        assert_eq!(p.leading_coefficient(), Some(&q(1, 2)));
+        assert_eq!(p.to_string(), "(1/2)x^2 - x + 3");
+        assert_eq!(
+            rpoly(&[(-2, 3), (0, 1), (-5, 4)]).to_string(),
+            "-(2/3)x^2 - 5/4"
+        );
+        assert_eq!(rpoly(&[(0, 1)]), RationalPolynomial::zero());
+        assert_eq!(RationalPolynomial::zero().to_string(), "0");
+        assert_eq!(
+            RationalPolynomial::from_ascending_coefficients(p.to_ascending_coefficients()),
+            p
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = rpoly(&[(1, 2), (1, 3)]);
+        let b = rpoly(&[(2, 1), (-2, 3)]);
+
+        // (x/2 + 1/3)(2x - 2/3) = x^2 + (1/3)x - 2/9
+        assert_eq!(a.mul(&b), rpoly(&[(1, 1), (1, 3), (-2, 9)]));
+        assert_eq!(a.add(&b), rpoly(&[(5, 2), (-1, 3)]));
+        assert_eq!(a.sub(&a), RationalPolynomial::zero());
+        assert_eq!(a.neg().add(&b).add(&a), b);
+        assert_eq!(a.scalar_mul(&q(6, 1)), rpoly(&[(3, 1), (2, 1)]));
+        assert!(a.scalar_mul(&q(0, 1)).is_zero());
+        assert!(a.mul(&RationalPolynomial::zero()).is_zero());
+        assert_eq!(a.evaluate(&q(2, 3)), q(2, 3));
+    }
+
+    #[test]
+    fn test_division() {
+        let a = rpoly(&[(1, 2), (1, 3)]);
+        let b = rpoly(&[(3, 1), (0, 1), (-1, 7)]);
+        let r = rpoly(&[(5, 4)]);
+        let n = a.mul(&b).add(&r);
+
+        let (quotient, remainder) = n.div(&a).unwrap();
+        assert_eq!((quotient, remainder), (b.clone(), r));
+        assert_eq!(a.mul(&b).exact_div(&b).unwrap(), a);
+        assert!(matches!(
+            n.exact_div(&a),
+            Err(PolynomialError::ArithmeticError { .. })
+        ));
+        assert!(matches!(
+            n.div(&RationalPolynomial::zero()),
+            Err(PolynomialError::DivisionByZero)
+        ));
+        assert_eq!(a.div(&b).unwrap(), (RationalPolynomial::zero(), a.clone()));
+
+        // 4x^2 - 1 = (2x + 1)(2x - 1) converts back exactly.
+        let two_x_plus_one = RationalPolynomial::from(&poly(&[2, 1]));
+        let quotient = RationalPolynomial::from(&poly(&[4, 0, -1]))
+            .exact_div(&two_x_plus_one)
+            .unwrap();
+        assert_eq!(quotient.to_polynomial().unwrap(), poly(&[2, -1]));
+        // x^2 divided by 2x gives x/2, which has no integer form.
+        let half = RationalPolynomial::from(&poly(&[1, 0, 0]))
+            .exact_div(&RationalPolynomial::from(&poly(&[2, 0])))
+            .unwrap();
+        assert_eq!(half, rpoly(&[(1, 2), (0, 1)]));
+        assert!(matches!(
+            half.to_polynomial(),
+            Err(PolynomialError::ArithmeticError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_interpolation() {
+        // y = x(x - 1)/2 through integer points has coefficients 1/2 and -1/2.
+        let points: Vec<_> = (0..4).map(|x| (q(x, 1), q(x * (x - 1) / 2, 1))).collect();
+        let p = RationalPolynomial::interpolate(&points).unwrap();
+        assert_eq!(p, rpoly(&[(1, 2), (-1, 2), (0, 1)]));
+        for (x, y) in &points {
+            assert_eq!(&p.evaluate(x), y);
+        }
+
+        let points = [(q(1, 2), q(3, 1)), (q(-2, 3), q(1, 5)), (q(7, 1), q(-4, 9))];
+        let p = RationalPolynomial::interpolate(&points).unwrap();
+        assert!(p.degree() <= 2);
+        for (x, y) in &points {
+            assert_eq!(&p.evaluate(x), y);
+        }
+
+        assert!(RationalPolynomial::interpolate(&[]).unwrap().is_zero());
+        assert_eq!(
+            RationalPolynomial::interpolate(&[(q(4, 1), q(-3, 2))]).unwrap(),
+            rpoly(&[(-3, 2)])
+        );
+        assert!(matches!(
+            RationalPolynomial::interpolate(&[(q(1, 1), q(0, 1)), (q(2, 2), q(1, 1))]),
+            Err(PolynomialError::ArithmeticError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_integer_conversions() {
+        let p = poly(&[3, 0, -7, 2]);
+        let r = RationalPolynomial::from_polynomial(&p);
+        assert_eq!(r.to_polynomial().unwrap(), p);
+        assert_eq!(r.clear_denominators(), (p, BigInt::from(1)));
+
+        let r = rpoly(&[(1, 6), (-3, 4), (2, 1)]);
+        let (scaled, d) = r.clear_denominators();
+        assert_eq!(d, BigInt::from(12));
+        assert_eq!(scaled, poly(&[2, -9, 24]));
+
+        let (scaled, d) = RationalPolynomial::zero().clear_denominators();
+        assert!(scaled.is_zero());
+        assert_eq!(d, BigInt::from(1));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialization_strips_leading_zeros() {
+        let a = rpoly(&[(1, 2), (0, 1), (-3, 4)]);
+        let bytes = bincode::serialize(&a).unwrap();
+        assert_eq!(
+            bincode::deserialize::<RationalPolynomial>(&bytes).unwrap(),
+            a
+        );
+
+        // A single-field struct is encoded as its field, so this is `[0, 1]` with a leading zero.
+        let bytes = bincode::serialize(&vec![q(0, 1), q(1, 1)]).unwrap();
+        let divisor: RationalPolynomial = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(divisor, rpoly(&[(1, 1)]));
+        assert_eq!(
+            a.div(&divisor).unwrap(),
+            (a.clone(), RationalPolynomial::zero())
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use bigint_poly::Polynomial;
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::rns::*;
+use bigint_poly::sampling::sample_uniform;
+use bigint_poly::utils::RoundingMode;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn basis(moduli: &[u64]) -> RnsBasis {
+        RnsBasis::new(moduli.iter().map(|&q| BigInt::from(q)).collect()).unwrap()
+    }
+
+    fn q_basis() -> RnsBasis {
+        basis(&[1_073_479_681, 1_073_184_769, 1_072_857_089])
+    }
+
+    fn p_basis() -> RnsBasis {
+        basis(&[1_071_513_601, 1_071_415_297])
+    }
+
+    #[test]
+    fn test_basis_validation() {
+        assert!(matches!(
+            RnsBasis::new(vec![]),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+        assert!(matches!(
+            RnsBasis::new(vec![BigInt::from(6), BigInt::from(9)]),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+        assert_eq!(basis(&[3, 5, 7]).product(), &BigInt::from(105));
+    }
+
+    #[test]
+    fn test_crt_roundtrip() {
+        let q = q_basis();
+        let mut rng = StdRng::seed_from_u64(0);
+        let poly = sample_uniform(31, q.product(), &mut rng);
+        let rns = RnsPolynomial::from_polynomial(&poly, &q);
+        assert_eq!(rns.limbs().len(), 3);
+        assert_eq!(rns.to_polynomial(&q), poly);
+    }
+
+    #[test]
+    fn test_fast_base_conversion_overflow_is_bounded() {
+        let q = q_basis();
+        let p = p_basis();
+        let mut rng = StdRng::seed_from_u64(1);
+        let poly = sample_uniform(31, q.product(), &mut rng);
+        let standard: Vec<BigInt> = poly
+            .coefficients()
+            .iter()
+            .map(|c| c.mod_floor(q.product()))
+            .collect();
+
+        let converted = RnsPolynomial::from_polynomial(&poly, &q).fast_base_conversion(&q, &p);
+        for (limb, pj) in converted.limbs().iter().zip(p.moduli()) {
+            for (x, y) in standard.iter().zip(limb.coefficients()) {
+                assert!((0..q.len()).any(|alpha| (x + q.product() * alpha).mod_floor(pj) == *y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_shenoy_kumaresan_conversion_is_exact() {
+        let q = q_basis();
+        let p = p_basis();
+        let m_sk = BigInt::from(1_070_727_169u64);
+        let mut rng = StdRng::seed_from_u64(2);
+        let poly = sample_uniform(31, q.product(), &mut rng);
+        let standard = Polynomial::new(
+            poly.coefficients()
+                .iter()
+                .map(|c| c.mod_floor(q.product()))
+                .collect(),
+        );
+
+        let redundant = RnsPolynomial::from_polynomial(&standard, &basis(&[1_070_727_169]));
+        let converted = RnsPolynomial::from_polynomial(&standard, &q)
+            .shenoy_kumaresan_conversion(&q, &m_sk, &redundant.limbs()[0], &p)
+            .unwrap();
+        assert_eq!(converted, RnsPolynomial::from_polynomial(&standard, &p));
+
+        assert!(matches!(
+            RnsPolynomial::from_polynomial(&standard, &q).shenoy_kumaresan_conversion(
+                &q,
+                &BigInt::from(2),
+                &redundant.limbs()[0],
+                &p
+            ),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+    }
+
+    /// Checks `scale_and_round_hps` against the exact `scale_and_round` modulo `t`.
+    fn assert_hps_matches_exact(poly: &Polynomial, q: &RnsBasis, t: &BigInt) {
+        let hps = RnsPolynomial::from_polynomial(poly, q)
+            .scale_and_round_hps(q, t)
+            .unwrap();
+        let exact = poly
+            .scale_and_round(t, q.product(), RoundingMode::NearestTiesAway)
+            .unwrap();
+        let exact: Vec<BigInt> = exact
+            .coefficients()
+            .iter()
+            .map(|c| c.mod_floor(t))
+            .collect();
+        assert_eq!(hps.coefficients(), exact.as_slice());
+    }
+
+    #[test]
+    fn test_scale_and_round_hps_matches_exact() {
+        let q = q_basis();
+        let t = BigInt::from(65537);
+        let mut rng = StdRng::seed_from_u64(3);
+        assert_hps_matches_exact(&sample_uniform(63, q.product(), &mut rng), &q, &t);
+    }
+
+    #[test]
+    fn test_scale_and_round_hps_with_60_bit_limbs() {
+        let q = basis(&[
+            1_152_921_504_606_584_833,
+            1_152_921_504_598_720_513,
+            1_152_921_504_597_016_577,
+        ]);
+        let t = BigInt::from(65537);
+        let mut rng = StdRng::seed_from_u64(4);
+        assert_hps_matches_exact(&sample_uniform(1023, q.product(), &mut rng), &q, &t);
+
+        // A BFV-shaped input Δ * m + e with Δ = floor(Q / t) and small noise.
+        let delta = q.product() / &t;
+        let m = sample_uniform(1023, &t, &mut rng);
+        let e = sample_uniform(1023, &BigInt::from(64), &mut rng);
+        let bfv = m.scalar_mul(&delta).add(&e).reduce_and_center(q.product());
+        assert_hps_matches_exact(&bfv, &q, &t);
+    }
+
+    #[test]
+    fn test_scale_and_round_hps_checks_limb_count() {
+        let q = q_basis();
+        let x = RnsPolynomial::from_polynomial(&Polynomial::new(vec![BigInt::from(5)]), &q);
+        assert!(matches!(
+            x.scale_and_round_hps(&p_basis(), &BigInt::from(17)),
+            Err(PolynomialError::InvalidPolynomial { .. })
+        ));
+    }
+}
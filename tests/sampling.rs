@@ -0,0 +1,45 @@
+use bigint_poly::sampling::*;
+use bigint_poly::utils::range_check_centered;
+use num_bigint::BigInt;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_uniform_is_centered() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let modulus = BigInt::from(97);
+        let poly = sample_uniform(63, &modulus, &mut rng);
+        assert_eq!(poly.coefficients().len(), 64);
+        assert!(range_check_centered(
+            poly.coefficients(),
+            &BigInt::from(-48),
+            &BigInt::from(48)
+        ));
+    }
+
+    #[test]
+    fn test_sample_ternary() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let poly = sample_ternary(127, &mut rng);
+        assert_eq!(poly.degree(), 127);
+        assert!(range_check_centered(
+            poly.coefficients(),
+            &BigInt::from(-1),
+            &BigInt::from(1)
+        ));
+    }
+
+    #[test]
+    fn test_sample_bounded() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let bound = BigInt::from(5);
+        let poly = sample_bounded(255, &bound, &mut rng);
+        assert!(range_check_centered(poly.coefficients(), &-&bound, &bound));
+        assert!(poly.coefficients().iter().any(|c| c == &bound));
+        assert!(poly.coefficients().iter().any(|c| c == &-&bound));
+    }
+}
@@ -0,0 +1,79 @@
+use bigint_poly::{Polynomial, SecretPolynomial};
+use num_bigint::BigInt;
+use zeroize::Zeroize;
+
+mod common;
+use common::poly;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_and_display_redact_coefficients() {
+        let secret = SecretPolynomial::new(poly(&[123456789, -987654321, 42]));
+
+        let debug = format!("{secret:?}");
+        let display = secret.to_string();
+        for s in [&debug, &display] {
+            assert!(!s.contains("123456789"));
+            assert!(!s.contains("987654321"));
+            assert!(!s.contains("42"));
+        }
+        assert_eq!(debug, "SecretPolynomial { degree: 2, .. }");
+        assert_eq!(display, "<secret polynomial of degree 2>");
+    }
+
+    #[test]
+    fn test_zeroize_clears_coefficients() {
+        let big: BigInt = BigInt::from(1) << 1000u32;
+        let mut secret = SecretPolynomial::new(Polynomial::new(vec![big.clone(), -big, 7.into()]));
+        secret.zeroize();
+        assert!(secret.expose_secret().is_zero());
+        assert!(secret.expose_secret().coefficients().is_empty());
+    }
+
+    #[test]
+    fn test_arithmetic_through_accessors() {
+        let s = poly(&[1, 0, -1]);
+        let a = poly(&[3, 5]);
+        let secret = SecretPolynomial::from(s.clone());
+
+        assert_eq!(secret.expose_secret(), &s);
+        assert_eq!(secret.add(&a).expose_secret(), &s.add(&a));
+        assert_eq!(secret.sub(&a).expose_secret(), &s.sub(&a));
+        assert_eq!(secret.mul(&a).expose_secret(), &s.mul(&a));
+        assert_eq!(secret.neg().expose_secret(), &s.neg());
+        assert_eq!(
+            secret.scalar_mul(&BigInt::from(4)).expose_secret(),
+            &s.scalar_mul(&BigInt::from(4))
+        );
+        assert_eq!(
+            secret
+                .scalar_mul(&BigInt::from(4))
+                .reduce_and_center(&BigInt::from(7))
+                .expose_secret(),
+            &poly(&[-3, 0, 3])
+        );
+
+        let cyclo = [BigInt::from(1), BigInt::from(0), BigInt::from(1)];
+        assert_eq!(
+            secret.reduce_by_cyclotomic(&cyclo).unwrap().expose_secret(),
+            &s.reduce_by_cyclotomic(&cyclo).unwrap()
+        );
+
+        let mut secret = secret;
+        *secret.expose_secret_mut() = a.clone();
+        assert_eq!(secret.expose_secret(), &a);
+        assert_eq!(secret.degree(), 1);
+    }
+
+    #[cfg(feature = "serde-secret")]
+    #[test]
+    fn test_serde_secret_roundtrip() {
+        let secret = SecretPolynomial::new(poly(&[1, -1, 0]));
+        let bytes = bincode::serialize(&secret).unwrap();
+        let decoded: SecretPolynomial = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded.expose_secret(), secret.expose_secret());
+    }
+}
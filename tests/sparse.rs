@@ -0,0 +1,118 @@
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::{Polynomial, SparsePolynomial};
+use num_bigint::BigInt;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+mod common;
+use common::ascending;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sparse(terms: &[(usize, i64)]) -> SparsePolynomial {
+        SparsePolynomial::new(terms.iter().map(|&(e, c)| (e, BigInt::from(c))).collect())
+    }
+
+    /// Reference product in Z[x]/(x^n + 1) via dense multiplication and reduction.
+    fn dense_negacyclic(a: &Polynomial, b: &Polynomial, n: usize) -> Polynomial {
+        let mut cyclo = vec![BigInt::from(0); n + 1];
+        cyclo[0] = BigInt::from(1);
+        cyclo[n] = BigInt::from(1);
+        let reduced = a.mul(b).reduce_by_cyclotomic(&cyclo).unwrap();
+        let mut coeffs = reduced.to_ascending_coefficients();
+        coeffs.resize(n, BigInt::from(0));
+        Polynomial::from_ascending_coefficients(coeffs)
+    }
+
+    #[test]
+    fn test_normalization_and_conversion() {
+        let s = sparse(&[(5, 2), (0, -1), (5, -2), (3, 4), (3, 1), (7, 0)]);
+        assert_eq!(s.terms(), &[(0, BigInt::from(-1)), (3, BigInt::from(5))]);
+        assert_eq!(s.hamming_weight(), 2);
+        assert_eq!(s.degree(), 3);
+        assert_eq!(s.to_polynomial(), ascending(&[-1, 0, 0, 5]));
+
+        let dense = ascending(&[0, 7, 0, 0, -3, 0]);
+        let from_dense = SparsePolynomial::from_polynomial(&dense);
+        assert_eq!(from_dense, sparse(&[(1, 7), (4, -3)]));
+        assert_eq!(from_dense.to_polynomial(), dense.trim_leading_zeros());
+
+        assert!(SparsePolynomial::zero().is_zero());
+        assert_eq!(SparsePolynomial::zero().to_polynomial(), ascending(&[0]));
+    }
+
+    #[test]
+    fn test_sparse_addition() {
+        let a = sparse(&[(0, 1), (2, 3), (9, -1)]);
+        let b = sparse(&[(1, 4), (2, -3), (9, 2), (12, 5)]);
+
+        assert_eq!(a.add(&b), sparse(&[(0, 1), (1, 4), (9, 1), (12, 5)]));
+        assert_eq!(
+            a.add(&b).to_polynomial(),
+            a.to_polynomial().add(&b.to_polynomial())
+        );
+        assert!(a.sub(&a).is_zero());
+        assert_eq!(
+            a.scalar_mul(&BigInt::from(-2)),
+            sparse(&[(0, -2), (2, -6), (9, 2)])
+        );
+        assert!(a.scalar_mul(&BigInt::from(0)).is_zero());
+    }
+
+    #[test]
+    fn test_mul_negacyclic_wraps_with_sign() {
+        // x^3 * (1 + 2x) = x^3 + 2x^4 = x^3 - 2 in Z[x]/(x^4 + 1).
+        let product = SparsePolynomial::monomial(3, BigInt::from(1))
+            .mul_negacyclic(&ascending(&[1, 2]), 4)
+            .unwrap();
+        assert_eq!(product, ascending(&[-2, 0, 0, 1]));
+
+        assert!(matches!(
+            sparse(&[(0, 1)]).mul_negacyclic(&ascending(&[1]), 6),
+            Err(PolynomialError::CyclotomicError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_mul_negacyclic_matches_dense() {
+        let n = 64;
+        let mut rng = StdRng::seed_from_u64(11);
+        for _ in 0..10 {
+            let dense = Polynomial::from_ascending_coefficients(
+                (0..n + 5)
+                    .map(|_| BigInt::from(rng.gen_range(-1000..1000)))
+                    .collect(),
+            );
+            let secret = SparsePolynomial::new(
+                (0..8)
+                    .map(|_| (rng.gen_range(0..3 * n), BigInt::from(rng.gen_range(-3..=3))))
+                    .collect(),
+            );
+
+            assert_eq!(
+                secret.mul_negacyclic(&dense, n).unwrap(),
+                dense_negacyclic(&secret.to_polynomial(), &dense, n)
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialization_normalizes_terms() {
+        let p = sparse(&[(0, 3), (5, -1)]);
+        let bytes = bincode::serialize(&p).unwrap();
+        assert_eq!(bincode::deserialize::<SparsePolynomial>(&bytes).unwrap(), p);
+
+        // A single-field struct is encoded as its field, so these are raw, unsorted terms.
+        let terms: Vec<(usize, BigInt)> = [(5, 1), (2, 1), (5, -2), (7, 0)]
+            .iter()
+            .map(|&(e, c)| (e, BigInt::from(c)))
+            .collect();
+        let bytes = bincode::serialize(&terms).unwrap();
+        let q: SparsePolynomial = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(q, sparse(&[(2, 1), (5, -1)]));
+        assert_eq!(q.to_polynomial(), ascending(&[0, 0, 1, 0, 0, -1]));
+    }
+}
@@ -0,0 +1,87 @@
+use bigint_poly::Polynomial;
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::field::FieldModulus;
+use bigint_poly::transcript::*;
+use num_bigint::BigInt;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_polynomial_is_length_prefixed() {
+        let short = Polynomial::new(vec![BigInt::from(1)]);
+        let padded = Polynomial::new(vec![BigInt::from(0), BigInt::from(1)]);
+        assert_ne!(encode_polynomial(&short), encode_polynomial(&padded));
+
+        assert_eq!(
+            encode_polynomial(&Polynomial::new(vec![BigInt::from(-258)])),
+            vec![1, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 2, 1]
+        );
+    }
+
+    #[test]
+    fn test_transcript_is_deterministic() {
+        let poly = Polynomial::new(vec![BigInt::from(3), BigInt::from(-4)]);
+        let modulus = BigInt::from(1_000_000_007u64);
+
+        let mut a = Transcript::new(b"protocol");
+        let mut b = Transcript::new(b"protocol");
+        a.append_polynomial(b"ct0", &poly);
+        b.append_polynomial(b"ct0", &poly);
+        assert_eq!(
+            a.challenge_bigint(b"gamma", &modulus).unwrap(),
+            b.challenge_bigint(b"gamma", &modulus).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_challenge_bigint_rejects_non_positive_modulus() {
+        let mut t = Transcript::new(b"protocol");
+        let mut untouched = t.clone();
+        for modulus in [0, -7] {
+            assert!(matches!(
+                t.challenge_bigint(b"gamma", &BigInt::from(modulus)),
+                Err(PolynomialError::ModulusError { .. })
+            ));
+        }
+        let modulus = BigInt::from(97);
+        assert_eq!(
+            t.challenge_bigint(b"gamma", &modulus).unwrap(),
+            untouched.challenge_bigint(b"gamma", &modulus).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_transcript_domain_separation() {
+        let poly = Polynomial::new(vec![BigInt::from(3), BigInt::from(-4)]);
+        let challenge = |protocol: &[u8], label: &[u8]| {
+            let mut t = Transcript::new(protocol);
+            t.append_polynomial(label, &poly);
+            let mut out = [0u8; 32];
+            t.challenge_bytes(b"c", &mut out);
+            out
+        };
+
+        assert_ne!(challenge(b"p1", b"a"), challenge(b"p2", b"a"));
+        assert_ne!(challenge(b"p1", b"a"), challenge(b"p1", b"b"));
+    }
+
+    #[test]
+    fn test_successive_challenges_differ() {
+        let field = FieldModulus::bn254();
+        let mut t = Transcript::new(b"protocol");
+        let first = t.challenge_field(b"c", &field);
+        let second = t.challenge_field(b"c", &field);
+        assert_ne!(first, second);
+        assert!(first < *field.modulus() && second < *field.modulus());
+    }
+
+    #[test]
+    fn test_challenge_bytes_long_output() {
+        let mut t = Transcript::new(b"protocol");
+        let mut out = [0u8; 100];
+        t.challenge_bytes(b"c", &mut out);
+        assert_ne!(out[..32], out[32..64]);
+    }
+}
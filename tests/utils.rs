@@ -1,3 +1,5 @@
+use bigint_poly::Polynomial;
+use bigint_poly::errors::PolynomialError;
 use bigint_poly::utils::*;
 use num_bigint::BigInt;
 
@@ -85,4 +87,190 @@ mod tests {
         let result = reduce_and_center_scalar(&x, &modulus);
         assert_eq!(result, BigInt::from(-1));
     }
+
+    #[test]
+    fn test_verify_ring_product() {
+        // x^2 + 1
+        let cyclo = vec![BigInt::from(1), BigInt::from(0), BigInt::from(1)];
+        let modulus = BigInt::from(7);
+        let b = Polynomial::new(vec![BigInt::from(3), BigInt::from(5)]);
+        let c = Polynomial::new(vec![BigInt::from(4), BigInt::from(6)]);
+
+        let reduction = b
+            .mul(&c)
+            .reduce_in_ring_with_quotients(&cyclo, &modulus)
+            .unwrap();
+        assert!(verify_ring_product(
+            &reduction.result,
+            &b,
+            &c,
+            &reduction.r1,
+            &reduction.r2,
+            &cyclo,
+            &modulus
+        ));
+        assert!(!verify_ring_product(
+            &reduction.result,
+            &b,
+            &c,
+            &reduction.r1,
+            &Polynomial::zero(0),
+            &cyclo,
+            &modulus
+        ));
+    }
+
+    #[test]
+    fn test_check_range_centered_reports_first_violation() {
+        let vec = vec![BigInt::from(1), BigInt::from(-5), BigInt::from(9)];
+        let lower = BigInt::from(-3);
+        let upper = BigInt::from(3);
+
+        match check_range_centered(&vec, &lower, &upper) {
+            Err(PolynomialError::RangeViolations { violations, .. }) => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].index, 1);
+                assert_eq!(violations[0].value, BigInt::from(-5));
+                assert_eq!(violations[0].lower_bound, lower);
+                assert_eq!(violations[0].upper_bound, upper);
+                assert_eq!(violations[0].modulus, None);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+        assert!(check_range_centered(&vec[..1], &lower, &upper).is_ok());
+    }
+
+    #[test]
+    fn test_check_range_standard() {
+        let modulus = BigInt::from(17);
+        let bound = BigInt::from(2);
+        assert!(
+            check_range_standard(&[BigInt::from(2), BigInt::from(15)], &bound, &modulus).is_ok()
+        );
+
+        let err = check_range_standard(&[BigInt::from(0), BigInt::from(14)], &bound, &modulus)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Range check error: coefficient 1 = 14 is outside [0, 2] ∪ [15, 17)"
+        );
+
+        assert!(
+            check_range_standard_2bounds(
+                &[BigInt::from(4), BigInt::from(16)],
+                &BigInt::from(-1),
+                &BigInt::from(4),
+                &modulus
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_range_report() {
+        let vec = vec![
+            BigInt::from(4),
+            BigInt::from(-1),
+            BigInt::from(-7),
+            BigInt::from(2),
+        ];
+        let report = range_report_centered(&vec, &BigInt::from(-2), &BigInt::from(2));
+        assert!(!report.is_ok());
+        assert_eq!(report.min, Some(BigInt::from(-7)));
+        assert_eq!(report.max, Some(BigInt::from(4)));
+        let indices: Vec<usize> = report.violations.iter().map(|v| v.index).collect();
+        assert_eq!(indices, vec![0, 2]);
+
+        match report.into_result() {
+            Err(PolynomialError::RangeViolations { violations, .. }) => {
+                assert_eq!(violations.len(), 2)
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        let report = range_report_standard(&[], &BigInt::from(1), &BigInt::from(7));
+        assert!(report.is_ok());
+        assert_eq!(report.min, None);
+        assert!(
+            range_report_standard_2bounds(
+                &[BigInt::from(6)],
+                &BigInt::from(-1),
+                &BigInt::from(0),
+                &BigInt::from(7)
+            )
+            .into_result()
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_ring_product_bound() {
+        let n = 4;
+        let mut cyclo = vec![BigInt::from(0); n + 1];
+        cyclo[0] = BigInt::from(1);
+        cyclo[n] = BigInt::from(1);
+
+        let a = Polynomial::new(vec![
+            BigInt::from(3),
+            BigInt::from(-3),
+            BigInt::from(3),
+            BigInt::from(-3),
+        ]);
+        let b = Polynomial::new(vec![
+            BigInt::from(-2),
+            BigInt::from(2),
+            BigInt::from(-2),
+            BigInt::from(2),
+        ]);
+        let product = a.mul(&b).reduce_by_cyclotomic(&cyclo).unwrap();
+
+        let bound = ring_product_bound(n, &a.infinity_norm(None), &b.infinity_norm(None));
+        assert_eq!(bound, BigInt::from(24));
+        assert!(product.infinity_norm(None) <= bound);
+    }
+
+    #[test]
+    fn test_divide_and_round_scalar_modes() {
+        let round = |n: i64, d: i64, mode| {
+            divide_and_round_scalar(&BigInt::from(n), &BigInt::from(d), mode)
+        };
+
+        // (numerator, denominator, ties-away, nearest-even, floor, ceil)
+        let cases = [
+            (7, 2, 4, 4, 3, 4),
+            (5, 2, 3, 2, 2, 3),
+            (-5, 2, -3, -2, -3, -2),
+            (-7, 2, -4, -4, -4, -3),
+            (-7, 3, -2, -2, -3, -2),
+            (7, -3, -2, -2, -3, -2),
+            (-6, 3, -2, -2, -2, -2),
+        ];
+        for (n, d, away, even, floor, ceil) in cases {
+            assert_eq!(
+                round(n, d, RoundingMode::NearestTiesAway),
+                BigInt::from(away)
+            );
+            assert_eq!(round(n, d, RoundingMode::NearestEven), BigInt::from(even));
+            assert_eq!(round(n, d, RoundingMode::Floor), BigInt::from(floor));
+            assert_eq!(round(n, d, RoundingMode::Ceil), BigInt::from(ceil));
+        }
+    }
+
+    #[test]
+    fn test_scale_and_round_scalar() {
+        let t = BigInt::from(3);
+        let q = BigInt::from(10);
+        assert_eq!(
+            scale_and_round_scalar(&BigInt::from(-5), &t, &q, RoundingMode::NearestTiesAway),
+            BigInt::from(-2)
+        );
+        assert_eq!(
+            scale_and_round_scalar(&BigInt::from(-5), &t, &q, RoundingMode::NearestEven),
+            BigInt::from(-2)
+        );
+        assert_eq!(
+            scale_and_round_scalar(&BigInt::from(5), &t, &q, RoundingMode::NearestEven),
+            BigInt::from(2)
+        );
+    }
 }
@@ -0,0 +1,155 @@
+use bigint_poly::errors::PolynomialError;
+use bigint_poly::zq::*;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+mod common;
+use common::poly;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Moduli of 2, 17, 61 and 62 bits.
+    const MODULI: [u64; 4] = [3, 65537, 0x1fff_ffff_e000_0001, 0x3fff_ffff_000a_0001];
+
+    fn mul_mod(a: u64, b: u64, q: u64) -> u64 {
+        (u128::from(a) * u128::from(b) % u128::from(q)) as u64
+    }
+
+    #[test]
+    fn test_invalid_moduli() {
+        for q in [0, 1, 2, 1 << 20, 1 << 62, u64::MAX] {
+            assert!(matches!(
+                Zq::new(q),
+                Err(PolynomialError::ModulusError { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_modulus_bit_limit() {
+        // 2^62 - 57 and 2^63 - 25 are the largest 62-bit and 63-bit primes.
+        let q = 0x3fff_ffff_ffff_ffc7;
+        let zq = Zq::new(q).unwrap();
+        assert_eq!(zq.mul(q - 1, q - 1), 1);
+        assert_eq!(zq.mul(q - 2, q - 3), 6);
+        assert_eq!(zq.pow(3, q - 1), 1);
+        assert!(matches!(
+            Zq::new(0x7fff_ffff_ffff_ffe7),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_reductions_match_naive() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for q in MODULI {
+            let zq = Zq::new(q).unwrap();
+            for _ in 0..1000 {
+                let x: u64 = rng.r#gen();
+                let a = rng.gen_range(0..q);
+                let b = rng.gen_range(0..q);
+                let expected = mul_mod(a, b, q);
+
+                assert_eq!(zq.reduce(x), x % q);
+                assert_eq!(zq.mul(a, b), expected);
+                assert_eq!(
+                    zq.from_montgomery(zq.mul_montgomery(zq.to_montgomery(a), zq.to_montgomery(b))),
+                    expected
+                );
+
+                let b_shoup = zq.shoup(b);
+                let lazy = zq.mul_shoup_lazy(x, b, b_shoup);
+                assert!(lazy < 2 * q);
+                assert_eq!(lazy % q, mul_mod(x % q, b, q));
+                assert_eq!(zq.mul_shoup(a, b, b_shoup), expected);
+
+                assert_eq!(
+                    zq.add(a, b),
+                    ((u128::from(a) + u128::from(b)) % u128::from(q)) as u64
+                );
+                assert_eq!(zq.add(zq.sub(a, b), b), a);
+                assert_eq!(zq.add(a, zq.neg(a)), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_and_inverse() {
+        let zq = Zq::new(65537).unwrap();
+        assert_eq!(zq.pow(3, 65536), 1);
+        assert_eq!(zq.pow(3, 32768), 65536);
+        assert_eq!(zq.mul(12345, zq.inv(12345).unwrap()), 1);
+        assert_eq!(zq.inv(0), None);
+        assert_eq!(Zq::new(15).unwrap().inv(5), None);
+    }
+
+    #[test]
+    fn test_polynomial_conversion_matches_reduce_and_center() {
+        for q in MODULI {
+            let zq = Zq::new(q).unwrap();
+            let p = poly(&[i64::MIN, -5, -1, 0, 1, 7, i64::MAX]);
+            let zp = ZqPolynomial::from_polynomial(&p, zq);
+
+            assert!(zp.coefficients().iter().all(|&c| c < q));
+            assert_eq!(zp.to_polynomial(), p.reduce_and_center(&BigInt::from(q)));
+            assert_eq!(ZqPolynomial::from_polynomial(&zp.to_polynomial(), zq), zp);
+        }
+    }
+
+    #[test]
+    fn test_polynomial_arithmetic_matches_bigint() {
+        let q = 0x3fff_ffff_000a_0001u64;
+        let modulus = BigInt::from(q);
+        let zq = Zq::new(q).unwrap();
+        let a = poly(&[i64::MAX, -3, 0, 11, -(1 << 40)]);
+        let b = poly(&[-7, 2, i64::MIN]);
+        let (za, zb) = (
+            ZqPolynomial::from_polynomial(&a, zq),
+            ZqPolynomial::from_polynomial(&b, zq),
+        );
+
+        assert_eq!(
+            za.add(&zb).unwrap().to_polynomial(),
+            a.add(&b).reduce_and_center(&modulus)
+        );
+        assert_eq!(
+            za.sub(&zb).unwrap().to_polynomial(),
+            a.sub(&b).reduce_and_center(&modulus)
+        );
+        assert_eq!(
+            za.neg().to_polynomial(),
+            a.neg().reduce_and_center(&modulus)
+        );
+        assert_eq!(
+            za.mul(&zb).unwrap().to_polynomial(),
+            a.mul(&b).reduce_and_center(&modulus)
+        );
+        assert_eq!(
+            za.scalar_mul(u64::MAX).to_polynomial(),
+            a.scalar_mul(&BigInt::from(u64::MAX))
+                .reduce_and_center(&modulus)
+        );
+        assert_eq!(
+            BigInt::from(za.evaluate(5)),
+            a.evaluate(&BigInt::from(5)).mod_floor(&modulus)
+        );
+    }
+
+    #[test]
+    fn test_mismatched_moduli() {
+        let a = ZqPolynomial::new(vec![1, 2], Zq::new(17).unwrap());
+        let b = ZqPolynomial::new(vec![1, 2], Zq::new(19).unwrap());
+        assert!(matches!(
+            a.add(&b),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+        assert!(matches!(
+            a.mul(&b),
+            Err(PolynomialError::ModulusError { .. })
+        ));
+    }
+}